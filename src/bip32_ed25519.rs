@@ -0,0 +1,124 @@
+use std::error::Error;
+use bitcoin::bip32::{ChildNumber, DerivationPath};
+use curve25519_dalek::{EdwardsPoint, Scalar};
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+use zeroize::Zeroizing;
+
+/// BIP32-Ed25519 extended key of Cardano wallets, the secret is kL || kR, kL is the ed25519 scalar
+pub struct ExtendedKey {
+    pub secret: Zeroizing<[u8; 64]>,
+    pub chain_code: Zeroizing<[u8; 32]>,
+}
+
+impl ExtendedKey {
+    /// Icarus master key of Yoroi, Daedalus and Eternl: PBKDF2-HMAC-SHA512 of the BIP39 entropy with an empty
+    /// passphrase, 4096 rounds, then the bits of kL are set as in ed25519
+    pub fn icarus_master(entropy: &[u8]) -> ExtendedKey {
+        let mut output = Zeroizing::new([0u8; 96]);
+        pbkdf2::pbkdf2_hmac::<Sha512>(b"", entropy, 4096, output.as_mut());
+
+        output[0] &= 0b1111_1000;
+        output[31] &= 0b0001_1111;
+        output[31] |= 0b0100_0000;
+
+        let mut secret = Zeroizing::new([0u8; 64]);
+        let mut chain_code = Zeroizing::new([0u8; 32]);
+
+        secret.copy_from_slice(&output[..64]);
+        chain_code.copy_from_slice(&output[64..]);
+
+        ExtendedKey { secret, chain_code }
+    }
+
+    pub fn derive(entropy: &[u8], path: &DerivationPath) -> Result<ExtendedKey, Box<dyn Error>> {
+        let mut key = ExtendedKey::icarus_master(entropy);
+
+        for child in path.into_iter() {
+            key = key.child(*child);
+        }
+
+        Ok(key)
+    }
+
+    /// Compressed Edwards point of kL
+    pub fn public_key(&self) -> [u8; 32] {
+        let mut scalar = Zeroizing::new([0u8; 32]);
+        scalar.copy_from_slice(&self.secret[..32]);
+
+        EdwardsPoint::mul_base(&Scalar::from_bytes_mod_order(*scalar)).compress().to_bytes()
+    }
+
+    /// Child key of the V2 scheme, hardened children hash the secret and normal ones the public key
+    fn child(&self, child: ChildNumber) -> ExtendedKey {
+        let (z, i) = match child {
+            ChildNumber::Hardened { index } => {
+                let index = (index | 0x8000_0000).to_le_bytes();
+                let data: [&[u8]; 2] = [self.secret.as_ref(), &index];
+
+                (self.hmac(0x00, &data), self.hmac(0x01, &data))
+            }
+            ChildNumber::Normal { index } => {
+                let index = index.to_le_bytes();
+                let public = self.public_key();
+                let data: [&[u8]; 2] = [&public, &index];
+
+                (self.hmac(0x02, &data), self.hmac(0x03, &data))
+            }
+        };
+
+        let mut secret = Zeroizing::new([0u8; 64]);
+
+        // kL = 8 * zL[0..28] + parent kL, kR = zR + parent kR mod 2^256, both little endian
+        let mut carry: u16 = 0;
+        for n in 0..32 {
+            let z_byte = if n < 28 { z[n] as u16 } else { 0 };
+            let z_prev = if n > 0 && n <= 28 { z[n - 1] as u16 } else { 0 };
+            let sum = self.secret[n] as u16 + ((z_byte << 3) & 0xff) + (z_prev >> 5) + carry;
+            secret[n] = sum as u8;
+            carry = sum >> 8;
+        }
+
+        let mut carry: u16 = 0;
+        for n in 0..32 {
+            let sum = self.secret[32 + n] as u16 + z[32 + n] as u16 + carry;
+            secret[32 + n] = sum as u8;
+            carry = sum >> 8;
+        }
+
+        let mut chain_code = Zeroizing::new([0u8; 32]);
+        chain_code.copy_from_slice(&i[32..]);
+
+        ExtendedKey { secret, chain_code }
+    }
+
+    fn hmac(&self, prefix: u8, data: &[&[u8]]) -> Zeroizing<[u8; 64]> {
+        let mut mac = Hmac::<Sha512>::new_from_slice(self.chain_code.as_ref()).unwrap();
+        mac.update(&[prefix]);
+
+        for chunk in data {
+            mac.update(chunk);
+        }
+
+        Zeroizing::new(<[u8; 64]>::from(mac.finalize().into_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bip39::Mnemonic;
+    use bitcoin::hex::DisplayHex;
+
+    /// The Icarus vector of CIP-3 without a passphrase, kL || kR || chain code
+    #[test]
+    fn icarus_master_vector() {
+        let mnemonic = Mnemonic::parse("eight country switch draw meat scout mystery blade tip drift useless good keep usage title").unwrap();
+        let key = ExtendedKey::icarus_master(&mnemonic.to_entropy());
+
+        assert_eq!(
+            format!("{}{}", key.secret.to_lower_hex_string(), key.chain_code.to_lower_hex_string()),
+            "c065afd2832cd8b087c4d9ab7011f481ee1e0721e78ea5dd609f3ab3f156d245d176bd8fd4ec60b4731c3918a2a72a0226c0cd119ec35b47e4d55884667f552a23f7fdcd4a10c6cd2c7393ac61d877873e248f417634aa3d812af327ffe9d620"
+        );
+    }
+}
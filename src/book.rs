@@ -0,0 +1,176 @@
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use crate::coins::registry::Registry;
+use crate::container;
+use crate::crypto::Cipher;
+use crate::explorers::Explorers;
+
+pub const BOOK_FILE: &str = "address_book.asc";
+
+const SEPARATOR: &str = "::";
+
+/// Public data of a derived key, nothing here allows spending
+pub struct BookEntry {
+    pub wallet: String,
+    pub index: u32,
+    pub address: String,
+    pub label: String,
+}
+
+impl BookEntry {
+    fn to_line(&self) -> String {
+        [self.wallet.clone(), self.index.to_string(), self.address.clone(), self.label.clone()].join(SEPARATOR)
+    }
+
+    fn from_line(line: &str) -> Result<BookEntry, Box<dyn Error>> {
+        let items: Vec<&str> = line.splitn(4, SEPARATOR).collect();
+
+        if items.len() < 3 {
+            return Err(format!("Wrong address book line {:?}", line).into());
+        }
+
+        Ok(BookEntry {
+            wallet: items[0].to_string(),
+            index: items[1].parse().map_err(|e| format!("Wrong index in address book line {:?}: {}", line, e))?,
+            address: items[2].to_string(),
+            label: items.get(3).unwrap_or(&"").to_string(),
+        })
+    }
+
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+
+        [&self.wallet, &self.index.to_string(), &self.address, &self.label]
+            .iter()
+            .any(|field| field.to_lowercase().contains(&query))
+    }
+
+    pub fn validate(&self) -> Result<(), Box<dyn Error>> {
+        for (name, value) in [("wallet", &self.wallet), ("address", &self.address), ("label", &self.label)] {
+            if value.contains(SEPARATOR) || value.contains('\n') {
+                return Err(format!("The {} can't contain {:?} or new lines", name, SEPARATOR).into());
+            }
+        }
+
+        if self.wallet.is_empty() || self.address.is_empty() {
+            return Err("Wallet and address are required".into());
+        }
+
+        Ok(())
+    }
+}
+
+/// Adds the entry unless the wallet already has its address, false when it was skipped
+pub fn add_entry(entries: &mut Vec<BookEntry>, entry: BookEntry) -> bool {
+    if entries.iter().any(|e| e.wallet == entry.wallet && e.address == entry.address) {
+        return false;
+    }
+
+    entries.push(entry);
+    true
+}
+
+pub fn load_book(path: &str, password: &str) -> Result<Vec<BookEntry>, Box<dyn Error>> {
+    if !Path::new(path).exists() {
+        return Ok(vec![]);
+    }
+
+    let armored = fs::read_to_string(path)
+        .map_err(|e| format!("Error on read file - {}: {}", path, e))?;
+    let text = String::from_utf8(container::decrypt(&armored, password)?.to_vec())?;
+
+    text.lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(BookEntry::from_line)
+        .collect()
+}
+
+pub fn save_book(path: &str, password: &str, entries: &[BookEntry]) -> Result<(), Box<dyn Error>> {
+    let text: String = entries.iter().map(|entry| entry.to_line() + "\n").collect();
+    let armored = container::encrypt(text.as_bytes(), password, Cipher::Aes256Gcm)?;
+
+    fs::write(path, armored).map_err(|e| format!("Error on write file - {}: {}", path, e).into())
+}
+
+/// The line of the entry and the link of the coin guessed from the wallet name
+pub fn print_entry(entry: &BookEntry, explorers: &Explorers, registry: &Registry) {
+    println!("{}", entry.to_line());
+
+    let link = match registry.for_name(&entry.wallet) {
+        Some(coin) => explorers.coin_link(&entry.wallet, coin, &entry.address),
+        None => explorers.link(&[&entry.wallet], &entry.address),
+    };
+
+    if let Some(link) = link {
+        println!("{}", link);
+    }
+
+    println!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(wallet: &str, index: u32, address: &str, label: &str) -> BookEntry {
+        BookEntry { wallet: wallet.to_string(), index, address: address.to_string(), label: label.to_string() }
+    }
+
+    fn lines(entries: &[BookEntry]) -> Vec<String> {
+        entries.iter().map(BookEntry::to_line).collect()
+    }
+
+    #[test]
+    fn save_and_load() {
+        let path = std::env::temp_dir().join(format!("bip39gen-book-{}.asc", std::process::id()));
+        let path = path.to_str().unwrap();
+        let entries = vec![entry("Electrum", 0, "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu", "savings"), entry("Phantom", 3, "HAgk14JpMQLgt6rVgv7cBQFJWFto5Dqxi472uT3DKpqk", "")];
+
+        assert!(load_book(path, "book password").unwrap().is_empty());
+
+        save_book(path, "book password", &entries).unwrap();
+        let loaded = load_book(path, "book password");
+        let wrong = load_book(path, "other password");
+        fs::remove_file(path).unwrap();
+
+        assert_eq!(lines(&loaded.unwrap()), lines(&entries));
+        assert!(wrong.is_err());
+    }
+
+    /// The label may contain the separator of the other fields, but not of its own
+    #[test]
+    fn line_format() {
+        let parsed = BookEntry::from_line("Electrum::7::bc1q...::cold::storage").unwrap();
+        assert_eq!((parsed.index, parsed.label.as_str()), (7, "cold::storage"));
+
+        assert_eq!(BookEntry::from_line("Electrum::7::bc1q...").unwrap().label, "");
+        assert!(BookEntry::from_line("Electrum::seven::bc1q...").is_err());
+        assert!(BookEntry::from_line("Electrum::7").is_err());
+
+        assert!(entry("Electrum", 0, "bc1q...", "a::b").validate().is_err());
+        assert!(entry("", 0, "bc1q...", "").validate().is_err());
+    }
+
+    #[test]
+    fn duplicates() {
+        let mut entries = vec![];
+
+        assert!(add_entry(&mut entries, entry("Electrum", 0, "bc1q...", "savings")));
+        assert!(!add_entry(&mut entries, entry("Electrum", 1, "bc1q...", "again")));
+        assert!(add_entry(&mut entries, entry("Sparrow", 0, "bc1q...", "")));
+        assert_eq!(entries.len(), 2);
+    }
+
+    #[test]
+    fn search() {
+        let entry = entry("Electrum", 12, "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu", "Cold Savings");
+
+        assert!(entry.matches("electrum"));
+        assert!(entry.matches("12"));
+        assert!(entry.matches("CR8TE4"));
+        assert!(entry.matches("savings"));
+        assert!(!entry.matches("phantom"));
+    }
+}
@@ -0,0 +1,124 @@
+use std::error::Error;
+use bitcoin::base58;
+use bitcoin::bech32::{self, Bech32, Hrp};
+use bitcoin::hashes::{hash160, Hash};
+use bitcoin::NetworkKind;
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+use crate::coins::{eth, expand_path, print_path_addresses, Coin, Curve, Options, Output, Seed};
+
+/// SLIP-44 coin type of the Avalanche X-Chain and P-Chain keys
+pub const COIN_TYPE: u32 = 9000;
+
+/// Address prefixes of Mainnet and of the Fuji testnet
+fn hrp(options: &Options) -> &'static str {
+    match options.network_kind() {
+        NetworkKind::Main => "avax",
+        NetworkKind::Test => "fuji",
+    }
+}
+
+/// X-Chain and P-Chain share one bech32 address of RIPEMD160(SHA256(compressed public key)),
+/// the C-Chain is an EVM chain with Ethereum addresses of `m/44'/60'/…`
+pub struct Avalanche {
+    c_chain: eth::Evm,
+}
+
+impl Default for Avalanche {
+    fn default() -> Self {
+        Avalanche { c_chain: eth::Evm::new("avax-c", eth::COIN_TYPE, Some("https://snowtrace.io/address/{address}")) }
+    }
+}
+
+impl Coin for Avalanche {
+    fn name(&self) -> &str {
+        "avax"
+    }
+
+    fn coin_type(&self) -> u32 {
+        COIN_TYPE
+    }
+
+    fn curve(&self, _options: &Options) -> Curve {
+        Curve::Secp256k1
+    }
+
+    fn path_template(&self, _options: &Options) -> String {
+        format!("m/44'/{}'/{{account}}'/0/{{index}}", COIN_TYPE)
+    }
+
+    fn address(&self, public_key: &[u8], options: &Options) -> Result<String, Box<dyn Error>> {
+        let hash = hash160::Hash::hash(public_key);
+
+        Ok(bech32::encode::<Bech32>(Hrp::parse(hrp(options))?, hash.as_byte_array())?)
+    }
+
+    /// `PrivateKey-` and CB58, base58 of the key with the last 4 bytes of its SHA-256, as Avalanche wallets export it
+    fn private_key(&self, secret: &[u8], _options: &Options) -> Result<Zeroizing<String>, Box<dyn Error>> {
+        let mut data = Zeroizing::new(secret.to_vec());
+        let checksum = Sha256::digest(&data[..]);
+        data.extend_from_slice(&checksum[28..]);
+
+        Ok(Zeroizing::new(format!("PrivateKey-{}", base58::encode(&data))))
+    }
+
+    fn explorer(&self) -> Option<&str> {
+        Some("https://avascan.info/blockchain/all/address/{address}")
+    }
+
+    /// C-Chain addresses first, then one key for the `X-` and `P-` forms of each X/P address
+    fn print_addresses(&self, seed: &Seed, out: &Output, options: &Options) -> Result<(), Box<dyn Error>> {
+        print_path_addresses(&self.c_chain, "avax C", seed, out, options)?;
+
+        for index in 0..options.gap {
+            let path = expand_path(&self.path_template(options), options.account, index);
+            let (secret, public) = self.curve(options).derive(seed, &path)?;
+            let address = self.address(&public, options)?;
+
+            out.address(self, "avax X", &path, &format!("X-{}", address));
+            out.address(self, "avax P", &path, &format!("P-{}", address));
+
+            if out.show.has("privkey") {
+                out.private_key(&self.private_key(&secret, options)?);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bip39::Mnemonic;
+    use bitcoin::hex::FromHex;
+    use bitcoin::secp256k1::{PublicKey, Secp256k1, SecretKey};
+    use crate::coins::Seed;
+
+    /// The ewoq key of avalanchejs and of local Avalanche networks
+    #[test]
+    fn ewoq_vector() {
+        let secret = Vec::from_hex("56289e99c94b6912bfc12adc093c9b51124f0dc54ac7a766b2bc5ccf558d8027").unwrap();
+        let public = PublicKey::from_secret_key(&Secp256k1::new(), &SecretKey::from_slice(&secret).unwrap());
+        let options = Options::default();
+        let avax = Avalanche::default();
+
+        assert_eq!(avax.address(&public.serialize(), &options).unwrap(), "avax18jma8ppw3nhx5r4ap8clazz0dps7rv5ukulre5");
+        assert_eq!(avax.private_key(&secret, &options).unwrap().as_str(), "PrivateKey-ewoqjP7PxY4yr3iLTpLisriqt94hdyDFNgchSxGGztUrTXtNN");
+        assert_eq!(eth::address(&public), "0x8db97C7cEcE249c2b98bDC0226Cc4C2A57BF52FC");
+    }
+
+    /// The C-Chain uses the Ethereum path, its first address is the Ethereum one
+    #[test]
+    fn c_chain_path() {
+        let mnemonic = Mnemonic::parse("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about").unwrap();
+        let (bytes, entropy) = (mnemonic.to_seed(""), mnemonic.to_entropy());
+        let seed = Seed { bytes: &bytes, entropy: &entropy };
+        let options = Options::default();
+        let c_chain = Avalanche::default().c_chain;
+
+        let (_, public) = c_chain.curve(&options).derive(&seed, &expand_path(&c_chain.path_template(&options), 0, 0)).unwrap();
+
+        assert_eq!(c_chain.address(&public, &options).unwrap(), "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+    }
+}
@@ -0,0 +1,141 @@
+use std::error::Error;
+use bitcoin::bech32::{self, Bech32, Hrp};
+use bitcoin::NetworkKind;
+use blake2::digest::consts::U28;
+use blake2::{Blake2b, Digest};
+use zeroize::Zeroizing;
+use crate::coins::{expand_path, Coin, Curve, Options, Output, Seed};
+
+/// SLIP-44 coin type of Cardano
+pub const COIN_TYPE: u32 = 1815;
+
+/// CIP-1852 purpose of Shelley wallets
+const PURPOSE: u32 = 1852;
+
+/// Role of the reward key in CIP-1852 paths, 0 is external payment keys and 1 is change
+const STAKE_ROLE: u32 = 2;
+
+/// CIP-19 address types in the high nibble of the header, the low nibble is the network id
+const BASE_ADDRESS: u8 = 0x00;
+const ENTERPRISE_ADDRESS: u8 = 0x60;
+const REWARD_ADDRESS: u8 = 0xe0;
+
+/// Shelley addresses of Icarus keys as in Yoroi, Eternl, Lace and Daedalus
+pub struct Cardano;
+
+impl Cardano {
+    /// Network id of the header and the address prefixes of CIP-5
+    fn network(options: &Options) -> (u8, &'static str, &'static str) {
+        match options.network_kind() {
+            NetworkKind::Main => (1, "addr", "stake"),
+            NetworkKind::Test => (0, "addr_test", "stake_test"),
+        }
+    }
+
+    /// Header byte and BLAKE2b-224 hashes of the public keys in bech32
+    fn encode(hrp: &str, header: u8, public_keys: &[&[u8]]) -> Result<String, Box<dyn Error>> {
+        let mut data = vec![header];
+
+        for public_key in public_keys {
+            data.extend_from_slice(&Blake2b::<U28>::digest(public_key));
+        }
+
+        Ok(bech32::encode::<Bech32>(Hrp::parse(hrp)?, &data)?)
+    }
+
+    /// CIP-5 bech32 of kL || kR || chain code, cardano-address and cardano-cli read it
+    fn extended_key(hrp: &str, secret: &[u8]) -> Result<Zeroizing<String>, Box<dyn Error>> {
+        Ok(Zeroizing::new(bech32::encode::<Bech32>(Hrp::parse(hrp)?, secret)?))
+    }
+}
+
+impl Coin for Cardano {
+    fn name(&self) -> &str {
+        "ada"
+    }
+
+    fn coin_type(&self) -> u32 {
+        COIN_TYPE
+    }
+
+    fn curve(&self, _options: &Options) -> Curve {
+        Curve::Ed25519Bip32
+    }
+
+    /// External payment keys
+    fn path_template(&self, _options: &Options) -> String {
+        format!("m/{}'/{}'/{{account}}'/0/{{index}}", PURPOSE, COIN_TYPE)
+    }
+
+    /// Enterprise address of the payment key alone, without staking rights
+    fn address(&self, public_key: &[u8], options: &Options) -> Result<String, Box<dyn Error>> {
+        let (network, hrp, _) = Cardano::network(options);
+
+        Cardano::encode(hrp, ENTERPRISE_ADDRESS | network, &[public_key])
+    }
+
+    fn private_key(&self, secret: &[u8], _options: &Options) -> Result<Zeroizing<String>, Box<dyn Error>> {
+        Cardano::extended_key("addr_xsk", secret)
+    }
+
+    fn explorer(&self) -> Option<&str> {
+        Some("https://cardanoscan.io/address/{address}")
+    }
+
+    /// Base addresses of payment keys `0..gap` with the account's stake key, then the reward address of the stake key
+    fn print_addresses(&self, seed: &Seed, out: &Output, options: &Options) -> Result<(), Box<dyn Error>> {
+        let (network, addr_hrp, stake_hrp) = Cardano::network(options);
+        let curve = self.curve(options);
+
+        let stake_template = format!("m/{}'/{}'/{{account}}'/{}/0", PURPOSE, COIN_TYPE, STAKE_ROLE);
+        let stake_path = expand_path(&stake_template, options.account, 0);
+        let (stake_secret, stake_public) = curve.derive(seed, &stake_path)?;
+
+        for index in 0..options.gap {
+            let path = expand_path(&self.path_template(options), options.account, index);
+            let (secret, public) = curve.derive(seed, &path)?;
+            let address = Cardano::encode(addr_hrp, BASE_ADDRESS | network, &[&public, &stake_public])?;
+
+            out.address(self, "ada", &path, &address);
+
+            if out.show.has("privkey") {
+                out.private_key(&self.private_key(&secret, options)?);
+            }
+        }
+
+        let reward_address = Cardano::encode(stake_hrp, REWARD_ADDRESS | network, &[&stake_public])?;
+        out.address(self, "ada stake", &stake_path, &reward_address);
+
+        if out.show.has("privkey") {
+            out.private_key(&Cardano::extended_key("stake_xsk", &stake_secret)?);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bip39::Mnemonic;
+
+    /// Base and reward addresses of m/1852'/1815'/0'/0/0 and m/1852'/1815'/0'/2/0 in the tests of cardano-serialization-lib
+    #[test]
+    fn cip1852_addresses() {
+        let mnemonic = Mnemonic::parse("test walk nut penalty hip pave soap entry language right filter choice").unwrap();
+        let (bytes, entropy) = (mnemonic.to_seed(""), mnemonic.to_entropy());
+        let seed = Seed { bytes: &bytes, entropy: &entropy };
+
+        let (_, payment) = Curve::Ed25519Bip32.derive(&seed, "m/1852'/1815'/0'/0/0").unwrap();
+        let (_, stake) = Curve::Ed25519Bip32.derive(&seed, "m/1852'/1815'/0'/2/0").unwrap();
+
+        assert_eq!(
+            Cardano::encode("addr", BASE_ADDRESS | 1, &[&payment, &stake]).unwrap(),
+            "addr1qx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer3jcu5d8ps7zex2k2xt3uqxgjqnnj83ws8lhrn648jjxtwqfjkjv7"
+        );
+        assert_eq!(
+            Cardano::encode("stake", REWARD_ADDRESS | 1, &[&stake]).unwrap(),
+            "stake1uyevw2xnsc0pvn9t9r9c7qryfqfeerchgrlm3ea2nefr9hqxdekzz"
+        );
+    }
+}
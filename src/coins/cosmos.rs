@@ -0,0 +1,110 @@
+use std::error::Error;
+use bitcoin::bech32::{self, Bech32, Hrp};
+use bitcoin::hashes::{hash160, Hash};
+use bitcoin::hex::DisplayHex;
+use bitcoin::secp256k1::PublicKey;
+use sha3::{Digest, Keccak256};
+use zeroize::Zeroizing;
+use crate::coins::{Coin, Curve, Options};
+
+/// SLIP-44 coin type of the Cosmos Hub, shared by most Cosmos-SDK chains
+pub const COIN_TYPE: u32 = 118;
+
+/// A Cosmos-SDK chain, addresses are the key hash in bech32 with the chain's prefix
+pub struct Cosmos {
+    name: String,
+    coin_type: u32,
+    hrp: String,
+    /// Ethereum-style keys of Injective and Evmos, the hash is the last 20 bytes of Keccak-256
+    eth_keys: bool,
+    explorer: Option<String>,
+}
+
+impl Cosmos {
+    pub fn new(name: &str, coin_type: u32, hrp: &str, eth_keys: bool, explorer: Option<&str>) -> Result<Cosmos, Box<dyn Error>> {
+        Hrp::parse(hrp).map_err(|e| format!("Wrong bech32 prefix {:?} of {}: {}", hrp, name, e))?;
+
+        Ok(Cosmos {
+            name: name.to_string(),
+            coin_type,
+            hrp: hrp.to_string(),
+            eth_keys,
+            explorer: explorer.map(String::from),
+        })
+    }
+
+    pub fn cosmos_hub() -> Cosmos {
+        Cosmos {
+            name: "atom".to_string(),
+            coin_type: COIN_TYPE,
+            hrp: "cosmos".to_string(),
+            eth_keys: false,
+            explorer: Some("https://www.mintscan.io/cosmos/address/{address}".to_string()),
+        }
+    }
+}
+
+impl Coin for Cosmos {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn coin_type(&self) -> u32 {
+        self.coin_type
+    }
+
+    fn curve(&self, _options: &Options) -> Curve {
+        Curve::Secp256k1
+    }
+
+    fn path_template(&self, _options: &Options) -> String {
+        format!("m/44'/{}'/{{account}}'/0/{{index}}", self.coin_type)
+    }
+
+    /// RIPEMD160(SHA256(compressed public key)), or Keccak-256 of the uncompressed one with Ethereum-style keys
+    fn address(&self, public_key: &[u8], _options: &Options) -> Result<String, Box<dyn Error>> {
+        let hash: Vec<u8> = if self.eth_keys {
+            let public_key = PublicKey::from_slice(public_key)?;
+            Keccak256::digest(&public_key.serialize_uncompressed()[1..])[12..].to_vec()
+        } else {
+            hash160::Hash::hash(public_key).as_byte_array().to_vec()
+        };
+
+        Ok(bech32::encode::<Bech32>(Hrp::parse(&self.hrp)?, &hash)?)
+    }
+
+    /// Hex of the secret key, Keplr and `<chain>d keys import-hex` take it as is
+    fn private_key(&self, secret: &[u8], _options: &Options) -> Result<Zeroizing<String>, Box<dyn Error>> {
+        Ok(Zeroizing::new(secret.to_lower_hex_string()))
+    }
+
+    fn explorer(&self) -> Option<&str> {
+        self.explorer.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bip39::Mnemonic;
+    use crate::coins::{expand_path, Seed};
+
+    #[test]
+    fn cosmos_hub_vector() {
+        let mnemonic = Mnemonic::parse("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about").unwrap();
+        let (bytes, entropy) = (mnemonic.to_seed(""), mnemonic.to_entropy());
+        let seed = Seed { bytes: &bytes, entropy: &entropy };
+        let options = Options::default();
+        let atom = Cosmos::cosmos_hub();
+
+        let (secret, public) = atom.curve(&options).derive(&seed, &expand_path(&atom.path_template(&options), 0, 0)).unwrap();
+
+        assert_eq!(atom.address(&public, &options).unwrap(), "cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4");
+        assert_eq!(atom.private_key(&secret, &options).unwrap().len(), 64);
+    }
+
+    #[test]
+    fn wrong_prefix() {
+        assert!(Cosmos::new("bad", COIN_TYPE, "Osmo 1", false, None).is_err());
+    }
+}
@@ -0,0 +1,148 @@
+use std::error::Error;
+use bitcoin::hex::DisplayHex;
+use bitcoin::secp256k1::PublicKey;
+use sha3::{Digest, Keccak256};
+use zeroize::Zeroizing;
+use crate::coins::{Coin, Curve, Options};
+
+/// SLIP-44 coin type of Ethereum, also used by EVM chains such as the Avalanche C-Chain
+pub const COIN_TYPE: u32 = 60;
+
+/// Derivation path layouts of popular wallets, `i` is the address index, EVM chains use their own coin type
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum PathStyle {
+    /// `m/44'/60'/account'/0/i` MetaMask, MyCrypto, Trezor, Exodus
+    Standard,
+    /// `m/44'/60'/i'/0/0` Ledger Live
+    LedgerLive,
+    /// `m/44'/60'/0'/i` legacy MyEtherWallet and Ledger Chrome app
+    Legacy,
+}
+
+impl PathStyle {
+    pub fn parse(name: &str) -> Result<PathStyle, Box<dyn Error>> {
+        match name.to_lowercase().as_str() {
+            "standard" | "bip44" => Ok(PathStyle::Standard),
+            "ledger-live" | "ledger" => Ok(PathStyle::LedgerLive),
+            "legacy" | "mew" => Ok(PathStyle::Legacy),
+            _ => Err(format!("Wrong ethereum path {:?}, expected standard, ledger-live or legacy", name).into()),
+        }
+    }
+
+    pub fn template(self, coin_type: u32) -> String {
+        match self {
+            PathStyle::Standard => format!("m/44'/{}'/{{account}}'/0/{{index}}", coin_type),
+            PathStyle::LedgerLive => format!("m/44'/{}'/{{index}}'/0/0", coin_type),
+            PathStyle::Legacy => format!("m/44'/{}'/0'/{{index}}", coin_type),
+        }
+    }
+}
+
+/// Ethereum or a chain with Ethereum keys and addresses, e.g. from chains.txt
+pub struct Evm {
+    name: String,
+    coin_type: u32,
+    explorer: Option<String>,
+}
+
+impl Evm {
+    pub fn new(name: &str, coin_type: u32, explorer: Option<&str>) -> Evm {
+        Evm { name: name.to_string(), coin_type, explorer: explorer.map(String::from) }
+    }
+
+    pub fn ethereum() -> Evm {
+        Evm::new("eth", COIN_TYPE, Some("https://etherscan.io/address/{address}"))
+    }
+}
+
+impl Coin for Evm {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn coin_type(&self) -> u32 {
+        self.coin_type
+    }
+
+    fn curve(&self, _options: &Options) -> Curve {
+        Curve::Secp256k1
+    }
+
+    fn path_template(&self, options: &Options) -> String {
+        options.eth_path.template(self.coin_type)
+    }
+
+    fn address(&self, public_key: &[u8], _options: &Options) -> Result<String, Box<dyn Error>> {
+        Ok(address(&PublicKey::from_slice(public_key)?))
+    }
+
+    fn private_key(&self, secret: &[u8], _options: &Options) -> Result<Zeroizing<String>, Box<dyn Error>> {
+        Ok(Zeroizing::new(format!("0x{}", Zeroizing::new(secret.to_lower_hex_string()).as_str())))
+    }
+
+    fn explorer(&self) -> Option<&str> {
+        self.explorer.as_deref()
+    }
+}
+
+/// Last 20 bytes of Keccak-256 of the uncompressed public key, with the EIP-55 checksum casing
+pub fn address(public_key: &PublicKey) -> String {
+    let hash = Keccak256::digest(&public_key.serialize_uncompressed()[1..]);
+
+    checksum_address(&hash[12..].to_lower_hex_string())
+}
+
+/// EIP-55: a hex letter is upper case when the same nibble of Keccak-256 of the lower case address is 8 or more
+fn checksum_address(hex: &str) -> String {
+    let hash = Keccak256::digest(hex.as_bytes());
+
+    let checksummed: String = hex.chars().enumerate().map(|(i, c)| {
+        let nibble = (hash[i / 2] >> if i % 2 == 0 { 4 } else { 0 }) & 0x0f;
+
+        if nibble >= 8 { c.to_ascii_uppercase() } else { c }
+    }).collect();
+
+    format!("0x{}", checksummed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bip39::Mnemonic;
+    use crate::coins::{expand_path, Seed};
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    fn addresses(eth_path: PathStyle) -> Vec<String> {
+        let mnemonic = Mnemonic::parse(MNEMONIC).unwrap();
+        let (bytes, entropy) = (mnemonic.to_seed(""), mnemonic.to_entropy());
+        let seed = Seed { bytes: &bytes, entropy: &entropy };
+        let options = Options { eth_path, ..Options::default() };
+        let eth = Evm::ethereum();
+
+        (0..2).map(|index| {
+            let (_, public) = eth.curve(&options).derive(&seed, &expand_path(&eth.path_template(&options), 0, index)).unwrap();
+            eth.address(&public, &options).unwrap()
+        }).collect()
+    }
+
+    #[test]
+    fn standard_vector() {
+        assert_eq!(addresses(PathStyle::Standard)[0], "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+    }
+
+    /// m/44'/60'/i'/0/0 and m/44'/60'/0'/i, the first Ledger Live address is the standard one
+    #[test]
+    fn ledger_live_and_legacy_paths() {
+        assert_eq!(addresses(PathStyle::LedgerLive), ["0x9858EfFD232B4033E47d90003D41EC34EcaEda94", "0x78839F6054d7ed13918bAe0473BA31b1Ca9D7265"]);
+        assert_eq!(addresses(PathStyle::Legacy), ["0xB8Fd42000d00202DCbCF5e18d6640d656345FD6A", "0x94381955F4028159A477a107510618aDb6B79Eb7"]);
+    }
+
+    /// Checksum test addresses of EIP-55
+    #[test]
+    fn eip55_casing() {
+        for expected in ["0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed", "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359", "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB"] {
+            assert_eq!(checksum_address(&expected[2..].to_lowercase()), expected);
+        }
+    }
+}
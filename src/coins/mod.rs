@@ -0,0 +1,318 @@
+pub mod avax;
+pub mod cardano;
+pub mod cosmos;
+pub mod eth;
+pub mod registry;
+pub mod sol;
+pub mod stellar;
+pub mod substrate;
+pub mod sui;
+pub mod ton;
+pub mod tron;
+pub mod utxo;
+pub mod xrp;
+
+use std::cell::RefCell;
+use std::error::Error;
+use std::path::Path;
+use std::str::FromStr;
+use bitcoin::bip32::{DerivationPath, Xpriv};
+use bitcoin::secp256k1::Secp256k1;
+use bitcoin::{Network, NetworkKind};
+use ed25519_dalek::SigningKey;
+use p256::elliptic_curve::sec1::ToEncodedPoint;
+use zeroize::Zeroizing;
+use crate::bip32_ed25519::ExtendedKey;
+use crate::book::{add_entry, BookEntry};
+use crate::console::extract_value;
+use crate::coins::utxo::AddressType;
+use crate::console::Show;
+use crate::crypto::SecretBytes;
+use crate::explorers::{fill_template, Explorers};
+use crate::keys::root_key;
+use crate::slip10::Ed25519Key;
+
+/// Derivation settings shared by all coins
+pub struct Options {
+    pub network: Network,
+    pub account: u32,
+    /// Count of receive and of change addresses printed for each key
+    pub gap: u32,
+    /// None is the default type of each chain
+    pub address_types: Option<Vec<AddressType>>,
+    pub eth_path: eth::PathStyle,
+    pub sol_path: sol::PathStyle,
+    pub sui_scheme: sui::Scheme,
+    /// Where Solana keypair files are written, nothing is written when it is not set
+    pub keypair_dir: Option<String>,
+    /// Junctions of Substrate accounts, `{index}` gives `gap` accounts
+    pub substrate_path: String,
+    /// None is the prefix of each Substrate chain
+    pub ss58_prefix: Option<u16>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            network: Network::Bitcoin,
+            account: 0,
+            gap: 5,
+            address_types: None,
+            eth_path: eth::PathStyle::Standard,
+            sol_path: sol::PathStyle::Standard,
+            sui_scheme: sui::Scheme::Ed25519,
+            keypair_dir: None,
+            substrate_path: String::new(),
+            ss58_prefix: None,
+        }
+    }
+}
+
+impl Options {
+    pub fn extract(args: &mut Vec<String>) -> Result<Options, Box<dyn Error>> {
+        let mut options = Options::default();
+
+        if let Some(value) = extract_value(args, "--network", "--network")? {
+            options.network = utxo::parse_network(&value)?;
+            println!("network = {}", options.network);
+        }
+
+        if let Some(value) = extract_value(args, "-a", "--account")? {
+            options.account = value.parse().map_err(|e| format!("Wrong account {:?}: {}", value, e))?;
+            println!("account = {}", options.account);
+        }
+
+        if let Some(value) = extract_value(args, "-g", "--gap")? {
+            options.gap = value.parse().map_err(|e| format!("Wrong gap {:?}: {}", value, e))?;
+            println!("gap = {}", options.gap);
+        }
+
+        if let Some(value) = extract_value(args, "-t", "--address_types")? {
+            options.address_types = Some(value.split(',').map(AddressType::parse).collect::<Result<_, _>>()?);
+            println!("address_types = {}", value);
+        }
+
+        if let Some(value) = extract_value(args, "--eth_path", "--eth_path")? {
+            options.eth_path = eth::PathStyle::parse(&value)?;
+            println!("eth_path = {}", value);
+        }
+
+        if let Some(value) = extract_value(args, "--sol_path", "--sol_path")? {
+            options.sol_path = sol::PathStyle::parse(&value)?;
+            println!("sol_path = {}", value);
+        }
+
+        if let Some(value) = extract_value(args, "--sui_scheme", "--sui_scheme")? {
+            options.sui_scheme = sui::Scheme::parse(&value)?;
+            println!("sui_scheme = {}", value);
+        }
+
+        if let Some(value) = extract_value(args, "--keypair_dir", "--keypair_dir")? {
+            if !Path::new(&value).is_dir() {
+                return Err(format!("Keypair directory {} doesn't exist", value).into());
+            }
+
+            println!("keypair_dir = {}", value);
+            options.keypair_dir = Some(value);
+        }
+
+        if let Some(value) = extract_value(args, "--substrate_path", "--substrate_path")? {
+            substrate::junctions(&expand_path(&value, options.account, 0))?;
+            println!("substrate_path = {}", value);
+            options.substrate_path = value;
+        }
+
+        if let Some(value) = extract_value(args, "--ss58_prefix", "--ss58_prefix")? {
+            let prefix = value.parse().map_err(|e| format!("Wrong ss58_prefix {:?}: {}", value, e))?;
+            substrate::check_prefix(prefix)?;
+            options.ss58_prefix = Some(prefix);
+            println!("ss58_prefix = {}", prefix);
+        }
+
+        Ok(options)
+    }
+
+    pub fn network_kind(&self) -> NetworkKind {
+        NetworkKind::from(self.network)
+    }
+
+    /// All test networks share the coin type 1
+    pub fn coin_type(&self, main_coin_type: u32) -> u32 {
+        match self.network_kind() {
+            NetworkKind::Main => main_coin_type,
+            NetworkKind::Test => 1,
+        }
+    }
+}
+
+/// BIP39 seed of a generated mnemonic, some chains derive their keys from the entropy of the words instead
+pub struct Seed<'a> {
+    pub bytes: &'a [u8],
+    pub entropy: &'a [u8],
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Curve {
+    /// BIP32
+    Secp256k1,
+    /// SLIP-10, hardened paths only
+    Ed25519,
+    /// Secret from secp256k1 BIP32 used as a P-256 key, the way the Sui SDKs derive their keys
+    Secp256r1,
+    /// BIP32-Ed25519 of Cardano from the Icarus master key of the entropy, the secret is kL || kR || chain code
+    Ed25519Bip32,
+    /// Schnorrkel keys of Substrate from the mini-secret of the entropy, the secret is the key and the nonce
+    Sr25519,
+}
+
+impl Curve {
+    /// Secret key and public key at the path, secp256k1 and P-256 public keys are compressed,
+    /// sr25519 paths are `//hard/soft` junctions and all other paths are BIP32 `m/…`
+    pub fn derive(self, seed: &Seed, path: &str) -> Result<(SecretBytes, Vec<u8>), Box<dyn Error>> {
+        match self {
+            Curve::Ed25519 => {
+                let secret = Ed25519Key::derive(seed.bytes, &parse_path(path)?)?.secret;
+                let public = SigningKey::from_bytes(&secret).verifying_key().to_bytes().to_vec();
+
+                Ok((Zeroizing::new(secret.to_vec()), public))
+            }
+            Curve::Secp256k1 | Curve::Secp256r1 => {
+                let secp = Secp256k1::new();
+                let root: Xpriv = root_key(seed.bytes, NetworkKind::Main)?;
+                let key = root.derive_priv(&secp, &parse_path(path)?)?.private_key;
+                let secret = Zeroizing::new(key.secret_bytes().to_vec());
+
+                let public = if self == Curve::Secp256k1 {
+                    key.public_key(&secp).serialize().to_vec()
+                } else {
+                    let p256_key = p256::SecretKey::from_slice(secret.as_ref())?;
+                    p256_key.public_key().to_encoded_point(true).as_bytes().to_vec()
+                };
+
+                Ok((secret, public))
+            }
+            Curve::Ed25519Bip32 => {
+                let key = ExtendedKey::derive(seed.entropy, &parse_path(path)?)?;
+
+                let mut secret = Zeroizing::new(key.secret.to_vec());
+                secret.extend_from_slice(key.chain_code.as_ref());
+
+                Ok((secret, key.public_key().to_vec()))
+            }
+            Curve::Sr25519 => substrate::derive(seed.entropy, path),
+        }
+    }
+}
+
+/// A chain the addresses can be derived for, see `registry::Registry` for the list
+pub trait Coin {
+    /// Short name, used in `coin=` of wallets.txt and in front of printed addresses
+    fn name(&self) -> &str;
+
+    /// SLIP-44 coin type
+    fn coin_type(&self) -> u32;
+
+    fn curve(&self, options: &Options) -> Curve;
+
+    /// Derivation path with `{account}` and `{index}` placeholders, one address is printed without `{index}`
+    fn path_template(&self, options: &Options) -> String;
+
+    fn address(&self, public_key: &[u8], options: &Options) -> Result<String, Box<dyn Error>>;
+
+    /// Private key in the format the wallets of the chain import
+    fn private_key(&self, secret: &[u8], options: &Options) -> Result<Zeroizing<String>, Box<dyn Error>>;
+
+    /// Block explorer URL template with `{address}`, the default link of the coin, explorers.txt can override it
+    fn explorer(&self) -> Option<&str> {
+        None
+    }
+
+    /// Addresses of indices `0..gap`, coins with several kinds of addresses print them their own way
+    fn print_addresses(&self, seed: &Seed, out: &Output, options: &Options) -> Result<(), Box<dyn Error>> {
+        print_path_addresses(self, self.name(), seed, out, options)
+    }
+}
+
+/// Fills `{account}` and `{index}` of a path template
+pub fn expand_path(template: &str, account: u32, index: u32) -> String {
+    template.replace("{account}", &account.to_string()).replace("{index}", &index.to_string())
+}
+
+pub fn parse_path(path: &str) -> Result<DerivationPath, Box<dyn Error>> {
+    DerivationPath::from_str(path).map_err(|e| format!("Wrong derivation path {:?}: {}", path, e).into())
+}
+
+/// The address and the private key of every index of the coin's path template, an empty path is shown as `root`
+pub fn print_path_addresses<C: Coin + ?Sized>(coin: &C, label: &str, seed: &Seed, out: &Output, options: &Options) -> Result<(), Box<dyn Error>> {
+    let template = coin.path_template(options);
+    let count = if template.contains("{index}") { options.gap } else { 1 };
+
+    for index in 0..count {
+        let path = expand_path(&template, options.account, index);
+        let (secret, public) = coin.curve(options).derive(seed, &path)?;
+        let name = if path.is_empty() { "root" } else { &path };
+
+        out.address(coin, label, name, &coin.address(&public, options)?);
+
+        if out.show.has("privkey") {
+            out.private_key(&coin.private_key(&secret, options)?);
+        }
+    }
+
+    Ok(())
+}
+
+/// Prints derived data of one wallet
+pub struct Output<'a> {
+    pub show: &'a Show,
+    wallet: &'a str,
+    explorers: Option<&'a Explorers>,
+    book: Option<(&'a RefCell<Vec<BookEntry>>, u32)>,
+}
+
+impl<'a> Output<'a> {
+    pub fn new(show: &'a Show, wallet: &'a str, explorers: Option<&'a Explorers>) -> Output<'a> {
+        Output { show, wallet, explorers, book: None }
+    }
+
+    /// Addresses of the key with `index` are also added to the address book, see `--save_book`
+    pub fn with_book(self, book: Option<&'a RefCell<Vec<BookEntry>>>, index: u32) -> Output<'a> {
+        Output { book: book.map(|book| (book, index)), ..self }
+    }
+
+    /// Address line, followed by the explorer link with `--show links`
+    pub fn address<C: Coin + ?Sized>(&self, coin: &C, label: &str, path: &str, address: &str) {
+        println!("    {} {}: {}", label, path, address);
+
+        self.save(&format!("{} {}", label, path), address);
+
+        if !self.show.has("links") {
+            return;
+        }
+
+        let link = match self.explorers {
+            Some(explorers) => explorers.coin_link(self.wallet, coin, address),
+            None => coin.explorer().map(|template| fill_template(template, address)),
+        };
+
+        if let Some(link) = link {
+            println!("      link: {}", link);
+        }
+    }
+
+    /// Adds the address to the address book with `--save_book`, unless the wallet already has it there
+    pub fn save(&self, label: &str, address: &str) {
+        if let Some((book, index)) = self.book {
+            add_entry(&mut book.borrow_mut(), BookEntry {
+                wallet: self.wallet.to_string(),
+                index,
+                address: address.to_string(),
+                label: label.to_string(),
+            });
+        }
+    }
+
+    pub fn private_key(&self, key: &str) {
+        println!("      privkey: {}", key);
+    }
+}
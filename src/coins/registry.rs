@@ -0,0 +1,272 @@
+use std::error::Error;
+use crate::coins::{avax, cardano, cosmos, eth, sol, stellar, substrate, sui, ton, tron, utxo, xrp, Coin};
+use crate::explorers::check_template;
+use crate::files::{lf, wf, WalletInfo};
+
+pub const CHAINS_FILE: &str = "chains.txt";
+
+/// Built-in coins and the chains of chains.txt, looked up by name or by SLIP-44 coin type
+pub struct Registry {
+    coins: Vec<Box<dyn Coin>>,
+}
+
+impl Registry {
+    fn defaults() -> Vec<String> {
+        vec![
+            "# name = SLIP-44 coin type[, explorer url template][, hrp=prefix][, keys=eth][, ss58=prefix]".to_string(),
+            "# chains with Ethereum keys and addresses, hrp= makes a Cosmos-SDK chain with bech32 addresses".to_string(),
+            "# and ss58= a Substrate chain with sr25519 keys".to_string(),
+            "bsc = 60, https://bscscan.com/address/{address}".to_string(),
+            "polygon = 60, https://polygonscan.com/address/{address}".to_string(),
+            "arbitrum = 60, https://arbiscan.io/address/{address}".to_string(),
+            "optimism = 60, https://optimistic.etherscan.io/address/{address}".to_string(),
+            "base = 60, https://basescan.org/address/{address}".to_string(),
+            "etc = 61, https://etc.blockscout.com/address/{address}".to_string(),
+            "osmosis = 118, https://www.mintscan.io/osmosis/address/{address}, hrp=osmo".to_string(),
+            "celestia = 118, https://www.mintscan.io/celestia/address/{address}, hrp=celestia".to_string(),
+            "juno = 118, https://www.mintscan.io/juno/address/{address}, hrp=juno".to_string(),
+            "akash = 118, https://www.mintscan.io/akash/address/{address}, hrp=akash".to_string(),
+            "terra = 330, https://finder.terra.money/mainnet/address/{address}, hrp=terra".to_string(),
+            "injective = 60, https://explorer.injective.network/account/{address}, hrp=inj, keys=eth".to_string(),
+        ]
+    }
+
+    /// Reads the chains, the file is created with the defaults when it is missing
+    pub fn load() -> Result<Registry, Box<dyn Error>> {
+        let lines: Vec<String> = match lf("./", CHAINS_FILE) {
+            Ok(data) => String::from_utf8(data)?.lines().map(String::from).collect(),
+            Err(_) => {
+                let lines = Registry::defaults();
+                wf("./", CHAINS_FILE, &(lines.join("\n") + "\n"))?;
+                lines
+            }
+        };
+
+        let mut registry = Registry::builtin();
+
+        for line in lines.iter().map(|line| line.trim()) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            registry.add(parse_chain(line)?)?;
+        }
+
+        Ok(registry)
+    }
+
+    fn builtin() -> Registry {
+        Registry {
+            coins: vec![
+                Box::new(utxo::BITCOIN),
+                Box::new(utxo::LITECOIN),
+                Box::new(utxo::DOGECOIN),
+                Box::new(utxo::BITCOIN_CASH),
+                Box::new(eth::Evm::ethereum()),
+                Box::new(avax::Avalanche::default()),
+                Box::new(sol::Solana),
+                Box::new(sui::Sui),
+                Box::new(cosmos::Cosmos::cosmos_hub()),
+                Box::new(tron::Tron),
+                Box::new(cardano::Cardano),
+                Box::new(substrate::Substrate::polkadot()),
+                Box::new(substrate::Substrate::kusama()),
+                Box::new(stellar::Stellar),
+                Box::new(xrp::Xrp),
+                Box::new(ton::Ton),
+            ],
+        }
+    }
+
+    fn add(&mut self, coin: Box<dyn Coin>) -> Result<(), Box<dyn Error>> {
+        if self.find(coin.name()).is_some() {
+            return Err(format!("Coin {} is defined twice, check {}", coin.name(), CHAINS_FILE).into());
+        }
+
+        self.coins.push(coin);
+
+        Ok(())
+    }
+
+    /// A number is a SLIP-44 coin type, the first coin of the type is taken, anything else is a name
+    pub fn find(&self, key: &str) -> Option<&dyn Coin> {
+        let key = key.trim().to_lowercase();
+
+        match key.parse::<u32>() {
+            Ok(coin_type) => self.coins.iter().find(|coin| coin.coin_type() == coin_type),
+            Err(_) => self.coins.iter().find(|coin| coin.name() == key),
+        }.map(|coin| coin.as_ref())
+    }
+
+    /// `coin=` of the wallet entry, otherwise a coin name among the words of the wallet name,
+    /// otherwise a guess from well-known wallet names, e.g. `Electrum` is a Bitcoin wallet
+    pub fn for_wallet(&self, item: &WalletInfo) -> Result<Option<&dyn Coin>, Box<dyn Error>> {
+        if let Some(key) = item.option("coin") {
+            return match self.find(key) {
+                Some(coin) => Ok(Some(coin)),
+                None => Err(format!("Wrong coin {:?} of {}, expected a name or a coin type from {}", key, item.name, CHAINS_FILE).into()),
+            };
+        }
+
+        Ok(self.for_name(&item.name))
+    }
+
+    /// A coin name among the words of a wallet name, otherwise a guess from well-known wallet names
+    pub fn for_name(&self, name: &str) -> Option<&dyn Coin> {
+        let name = name.to_lowercase();
+        let words: Vec<&str> = name.split(|c: char| !c.is_alphanumeric()).filter(|word| !word.is_empty()).collect();
+
+        if let Some(coin) = words.iter().find_map(|word| self.find_name(word)) {
+            return Some(coin);
+        }
+
+        guess_coin(&words).and_then(|key| self.find_name(key))
+    }
+
+    fn find_name(&self, name: &str) -> Option<&dyn Coin> {
+        self.coins.iter().find(|coin| coin.name() == name).map(|coin| coin.as_ref())
+    }
+}
+
+/// `name = coin type, explorer, key=value...`, the explorer and the keys are optional,
+/// `hrp=` makes a Cosmos-SDK chain and `keys=eth` gives it Ethereum-style keys, `ss58=` makes a Substrate chain
+fn parse_chain(line: &str) -> Result<Box<dyn Coin>, Box<dyn Error>> {
+    let wrong_line = || format!("Wrong line in {}: {:?}", CHAINS_FILE, line);
+
+    let (name, params) = line.split_once('=').ok_or_else(wrong_line)?;
+    let name = name.trim().to_lowercase();
+
+    if name.is_empty() || name.parse::<u32>().is_ok() || !name.chars().all(|c| c.is_alphanumeric() || c == '-') {
+        return Err(wrong_line().into());
+    }
+
+    let mut fields = params.split(',').map(|field| field.trim());
+    let coin_type: u32 = fields.next().unwrap_or_default().parse().map_err(|_| wrong_line())?;
+
+    if coin_type >= 0x8000_0000 {
+        return Err(wrong_line().into());
+    }
+
+    let mut explorer: Option<&str> = None;
+    let mut hrp: Option<&str> = None;
+    let mut eth_keys = false;
+    let mut ss58: Option<u16> = None;
+
+    for field in fields {
+        if field.contains('{') {
+            check_template(field, CHAINS_FILE, line)?;
+            explorer = Some(field);
+            continue;
+        }
+
+        match field.split_once('=').map(|(key, value)| (key.trim(), value.trim())) {
+            Some(("hrp", value)) => hrp = Some(value),
+            Some(("keys", "eth")) => eth_keys = true,
+            Some(("keys", "cosmos")) => eth_keys = false,
+            Some(("ss58", value)) => ss58 = Some(value.parse().map_err(|_| wrong_line())?),
+            _ => return Err(format!("Wrong field {:?} in {}, expected an explorer with {{address}}, hrp=, keys=eth or ss58=: {:?}", field, CHAINS_FILE, line).into()),
+        }
+    }
+
+    match (hrp, ss58) {
+        (Some(_), Some(_)) => Err(format!("hrp= and ss58= can't be used together in {}: {:?}", CHAINS_FILE, line).into()),
+        (Some(hrp), None) => Ok(Box::new(cosmos::Cosmos::new(&name, coin_type, hrp, eth_keys, explorer)?)),
+        (None, _) if eth_keys => Err(format!("keys=eth needs hrp= in {}: {:?}", CHAINS_FILE, line).into()),
+        (None, Some(prefix)) => Ok(Box::new(substrate::Substrate::new(&name, coin_type, prefix, explorer)?)),
+        (None, None) => Ok(Box::new(eth::Evm::new(&name, coin_type, explorer))),
+    }
+}
+
+/// Words of wallet names of the built-in coins, whole words only, so `Solar` is not Solana and `Atomic` is not Cosmos
+fn guess_coin(words: &[&str]) -> Option<&'static str> {
+    // Before Bitcoin, "Bitcoin-Cash" is two words and they are also tried together
+    const GUESSES: &[(&[&str], &str)] = &[
+        (&["bch", "bitcoincash"], "bch"),
+        (&["electrum", "bitcoin", "btc"], "btc"),
+        (&["doge", "dogecoin"], "doge"),
+        (&["litecoin", "ltc"], "ltc"),
+        (&["avax", "avalanche"], "avax"),
+        (&["ethereum", "eth", "mycrypto", "metamask"], "eth"),
+        (&["solana", "sol", "phantom"], "sol"),
+        (&["sui"], "sui"),
+        (&["cosmos", "atom", "keplr"], "atom"),
+        (&["tron", "trx", "tronlink"], "trx"),
+        (&["cardano", "yoroi", "eternl", "daedalus"], "ada"),
+        (&["kusama"], "ksm"),
+        (&["polkadot", "talisman", "subwallet"], "dot"),
+        (&["stellar", "lobstr", "freighter"], "xlm"),
+        (&["ripple", "xaman", "xumm"], "xrp"),
+    ];
+
+    let pairs: Vec<String> = words.windows(2).map(|pair| pair.concat()).collect();
+
+    GUESSES.iter()
+        .find(|(keys, _)| keys.iter().any(|key| words.contains(key) || pairs.iter().any(|pair| pair == key)))
+        .map(|(_, coin)| *coin)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bip39::Mnemonic;
+    use bitcoin::bech32;
+    use bitcoin::hex::DisplayHex;
+    use crate::coins::{expand_path, Options, Seed};
+
+    fn guess(name: &str) -> Option<String> {
+        Registry::builtin().for_name(name).map(|coin| coin.name().to_string())
+    }
+
+    #[test]
+    fn guesses_whole_words() {
+        assert_eq!(guess("Electrum-Native").as_deref(), Some("btc"));
+        assert_eq!(guess("Bitcoin-Cash").as_deref(), Some("bch"));
+        assert_eq!(guess("Ethereum-MyCrypto").as_deref(), Some("eth"));
+        assert_eq!(guess("Sui-Atomic").as_deref(), Some("sui"));
+        assert_eq!(guess("TronLink").as_deref(), Some("trx"));
+        assert_eq!(guess("Ledger ADA").as_deref(), Some("ada"));
+    }
+
+    #[test]
+    fn ignores_parts_of_words() {
+        assert_eq!(guess("Solar"), None);
+        assert_eq!(guess("Atomic"), None);
+        assert_eq!(guess("Console"), None);
+        assert_eq!(guess("Method"), None);
+    }
+
+    /// The address of the first key of a chains.txt line, for the abandon…about mnemonic
+    fn first_address(line: &str) -> String {
+        let mnemonic = Mnemonic::parse("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about").unwrap();
+        let (bytes, entropy) = (mnemonic.to_seed(""), mnemonic.to_entropy());
+        let seed = Seed { bytes: &bytes, entropy: &entropy };
+        let options = Options::default();
+        let coin = parse_chain(line).unwrap();
+
+        let (_, public) = coin.curve(&options).derive(&seed, &expand_path(&coin.path_template(&options), 0, 0)).unwrap();
+        coin.address(&public, &options).unwrap()
+    }
+
+    /// `hrp=` keeps the Cosmos Hub key hash, `keys=eth` gives the Ethereum address hash of the chain's coin type
+    #[test]
+    fn cosmos_chain_lines() {
+        let osmosis = first_address("osmosis = 118, https://www.mintscan.io/osmosis/address/{address}, hrp=osmo");
+        let (hrp, hash) = bech32::decode(&osmosis).unwrap();
+        let (_, hub_hash) = bech32::decode("cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4").unwrap();
+
+        assert_eq!((hrp.as_str(), hash), ("osmo", hub_hash));
+
+        let injective = first_address("injective = 60, https://explorer.injective.network/account/{address}, hrp=inj, keys=eth");
+        let (hrp, hash) = bech32::decode(&injective).unwrap();
+
+        assert_eq!((hrp.as_str(), hash.to_lower_hex_string()), ("inj", "9858effd232b4033e47d90003d41ec34ecaeda94".to_string()));
+    }
+
+    #[test]
+    fn wrong_chain_lines() {
+        assert!(parse_chain("osmosis = 118, hrp=osmo, ss58=0").is_err());
+        assert!(parse_chain("bsc = 60, keys=eth").is_err());
+        assert!(parse_chain("118 = 118").is_err());
+    }
+}
+
@@ -0,0 +1,185 @@
+use std::error::Error;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use bitcoin::base58;
+use ed25519_dalek::SigningKey;
+use zeroize::Zeroizing;
+use crate::coins::{expand_path, parse_path, Coin, Curve, Options, Output, Seed};
+use crate::slip10::Ed25519Key;
+
+/// SLIP-44 coin type of Solana
+pub const COIN_TYPE: u32 = 501;
+
+/// Derivation path layouts of popular wallets, `i` is the account index
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum PathStyle {
+    /// `m/44'/501'/i'/0'` Phantom, Solflare, Exodus, Backpack
+    Standard,
+    /// `m/44'/501'/i'` Ledger and Solflare's second option
+    Ledger,
+    /// `m/44'/501'` solana-keygen with `--derivation-path` and no value
+    Cli,
+    /// The first 32 bytes of the BIP39 seed without derivation, solana-keygen without `--derivation-path`
+    Seed,
+}
+
+impl PathStyle {
+    pub fn parse(name: &str) -> Result<PathStyle, Box<dyn Error>> {
+        match name.to_lowercase().as_str() {
+            "standard" | "phantom" => Ok(PathStyle::Standard),
+            "ledger" => Ok(PathStyle::Ledger),
+            "cli" => Ok(PathStyle::Cli),
+            "seed" => Ok(PathStyle::Seed),
+            _ => Err(format!("Wrong solana path {:?}, expected standard, ledger, cli or seed", name).into()),
+        }
+    }
+
+    /// None for the raw seed, which has no path
+    fn template(self) -> Option<String> {
+        match self {
+            PathStyle::Standard => Some(format!("m/44'/{}'/{{index}}'/0'", COIN_TYPE)),
+            PathStyle::Ledger => Some(format!("m/44'/{}'/{{index}}'", COIN_TYPE)),
+            PathStyle::Cli => Some(format!("m/44'/{}'", COIN_TYPE)),
+            PathStyle::Seed => None,
+        }
+    }
+
+    /// Paths without an index give one key only
+    fn indexed(self) -> bool {
+        matches!(self, PathStyle::Standard | PathStyle::Ledger)
+    }
+}
+
+/// Base58 public keys of ed25519 keys, the private key is the base58 keypair Phantom and Solflare import
+pub struct Solana;
+
+impl Coin for Solana {
+    fn name(&self) -> &str {
+        "sol"
+    }
+
+    fn coin_type(&self) -> u32 {
+        COIN_TYPE
+    }
+
+    fn curve(&self, _options: &Options) -> Curve {
+        Curve::Ed25519
+    }
+
+    /// The raw seed style has no path, its key is printed as `seed`
+    fn path_template(&self, options: &Options) -> String {
+        options.sol_path.template().unwrap_or_else(|| "m".to_string())
+    }
+
+    fn address(&self, public_key: &[u8], _options: &Options) -> Result<String, Box<dyn Error>> {
+        Ok(base58::encode(public_key))
+    }
+
+    fn private_key(&self, secret: &[u8], _options: &Options) -> Result<Zeroizing<String>, Box<dyn Error>> {
+        let keypair = Zeroizing::new(SigningKey::from_bytes(secret.try_into()?).to_keypair_bytes());
+
+        Ok(Zeroizing::new(base58::encode(keypair.as_ref())))
+    }
+
+    fn explorer(&self) -> Option<&str> {
+        Some("https://solscan.io/account/{address}")
+    }
+
+    /// Accounts `0..gap`, also written as solana-keygen keypair files with `--keypair_dir`
+    fn print_addresses(&self, seed: &Seed, out: &Output, options: &Options) -> Result<(), Box<dyn Error>> {
+        let count = if options.sol_path.indexed() { options.gap } else { 1 };
+
+        for index in 0..count {
+            let (name, secret) = match options.sol_path.template() {
+                Some(template) => {
+                    let path = parse_path(&expand_path(&template, options.account, index))?;
+                    (format!("m/{}", path), Ed25519Key::derive(seed.bytes, &path)?.secret)
+                }
+                None => {
+                    let mut secret = Zeroizing::new([0u8; 32]);
+                    secret.copy_from_slice(&seed.bytes[..32]);
+
+                    ("seed".to_string(), secret)
+                }
+            };
+
+            let keypair = Zeroizing::new(SigningKey::from_bytes(&secret).to_keypair_bytes());
+            let address = base58::encode(&keypair[32..]);
+
+            out.address(self, "sol", &name, &address);
+
+            if out.show.has("privkey") {
+                out.private_key(&self.private_key(secret.as_ref(), options)?);
+            }
+
+            if let Some(dir) = &options.keypair_dir {
+                let file = Path::new(dir).join(format!("{}.json", address));
+                write_keypair(&file, keypair.as_ref())?;
+                println!("      keypair: {}", file.display());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// JSON array of the 64 keypair bytes, the format of solana-keygen, readable by the owner only
+fn write_keypair(file: &Path, keypair: &[u8]) -> Result<(), Box<dyn Error>> {
+    let bytes: Vec<String> = keypair.iter().map(|b| b.to_string()).collect();
+    let json = Zeroizing::new(format!("[{}]", bytes.join(",")));
+
+    let mut open = fs::OpenOptions::new();
+    open.write(true).create(true).truncate(true);
+
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut open, 0o600);
+
+    open.open(file)
+        .and_then(|mut f| f.write_all(json.as_bytes()))
+        .map_err(|e| format!("Error on write file - {}: {}", file.display(), e).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bip39::Mnemonic;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    fn secret() -> Zeroizing<[u8; 32]> {
+        let seed = Mnemonic::parse(MNEMONIC).unwrap().to_seed("");
+        let path = expand_path(&PathStyle::Standard.template().unwrap(), 0, 0);
+
+        Ed25519Key::derive(&seed, &parse_path(&path).unwrap()).unwrap().secret
+    }
+
+    #[test]
+    fn phantom_vector() {
+        let options = Options::default();
+        let public = SigningKey::from_bytes(&secret()).verifying_key().to_bytes();
+
+        assert_eq!(Solana.address(&public, &options).unwrap(), "HAgk14JpMQLgt6rVgv7cBQFJWFto5Dqxi472uT3DKpqk");
+    }
+
+    /// The file solana-keygen reads back is the keypair of the same address
+    #[test]
+    fn keypair_file_round_trip() {
+        let keypair = SigningKey::from_bytes(&secret()).to_keypair_bytes();
+        let file = std::env::temp_dir().join(format!("bip39gen-keypair-{}.json", std::process::id()));
+
+        write_keypair(&file, &keypair).unwrap();
+        let json = fs::read_to_string(&file).unwrap();
+
+        #[cfg(unix)]
+        assert_eq!(std::os::unix::fs::PermissionsExt::mode(&fs::metadata(&file).unwrap().permissions()) & 0o777, 0o600);
+
+        fs::remove_file(&file).unwrap();
+
+        let bytes: Vec<u8> = json.trim_start_matches('[').trim_end_matches(']').split(',').map(|b| b.parse().unwrap()).collect();
+        let read = SigningKey::from_keypair_bytes(bytes.as_slice().try_into().unwrap()).unwrap();
+
+        assert_eq!(read.to_keypair_bytes(), keypair);
+        assert_eq!(base58::encode(read.verifying_key().as_bytes()), "HAgk14JpMQLgt6rVgv7cBQFJWFto5Dqxi472uT3DKpqk");
+    }
+}
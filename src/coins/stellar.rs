@@ -0,0 +1,115 @@
+use std::error::Error;
+use zeroize::Zeroizing;
+use crate::coins::{Coin, Curve, Options};
+
+/// SLIP-44 coin type of Stellar
+pub const COIN_TYPE: u32 = 148;
+
+/// Strkey version bytes, they give the `G` of account ids and the `S` of secret seeds
+const ACCOUNT_ID: u8 = 6 << 3;
+const SECRET_SEED: u8 = 18 << 3;
+
+/// SEP-0005 accounts, SLIP-10 ed25519 keys on `m/44'/148'/i'` as in Lobstr, Freighter, Solar and Ledger
+pub struct Stellar;
+
+impl Coin for Stellar {
+    fn name(&self) -> &str {
+        "xlm"
+    }
+
+    fn coin_type(&self) -> u32 {
+        COIN_TYPE
+    }
+
+    fn curve(&self, _options: &Options) -> Curve {
+        Curve::Ed25519
+    }
+
+    fn path_template(&self, _options: &Options) -> String {
+        format!("m/44'/{}'/{{index}}'", COIN_TYPE)
+    }
+
+    fn address(&self, public_key: &[u8], _options: &Options) -> Result<String, Box<dyn Error>> {
+        Ok(strkey(ACCOUNT_ID, public_key))
+    }
+
+    fn private_key(&self, secret: &[u8], _options: &Options) -> Result<Zeroizing<String>, Box<dyn Error>> {
+        Ok(Zeroizing::new(strkey(SECRET_SEED, secret)))
+    }
+
+    fn explorer(&self) -> Option<&str> {
+        Some("https://stellar.expert/explorer/public/account/{address}")
+    }
+}
+
+/// Base32 of the version byte, the key and its CRC16-XModem in little endian
+fn strkey(version: u8, key: &[u8]) -> String {
+    const ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+    let mut data = Zeroizing::new(vec![version]);
+    data.extend_from_slice(key);
+    let checksum = crc16_xmodem(&data);
+    data.extend_from_slice(&checksum.to_le_bytes());
+
+    let mut encoded = String::new();
+    let mut acc: u32 = 0;
+    let mut bits = 0;
+
+    for &byte in data.iter() {
+        acc = (acc << 8) | byte as u32;
+        bits += 8;
+
+        while bits >= 5 {
+            bits -= 5;
+            encoded.push(ALPHABET[((acc >> bits) & 0x1f) as usize] as char);
+        }
+    }
+
+    if bits > 0 {
+        encoded.push(ALPHABET[((acc << (5 - bits)) & 0x1f) as usize] as char);
+    }
+
+    encoded
+}
+
+fn crc16_xmodem(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0;
+
+    for &byte in data {
+        crc ^= (byte as u16) << 8;
+
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 { (crc << 1) ^ 0x1021 } else { crc << 1 };
+        }
+    }
+
+    crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bip39::Mnemonic;
+    use crate::coins::{expand_path, Seed};
+
+    /// Test 1 of SEP-0005
+    #[test]
+    fn sep5_vector() {
+        let mnemonic = Mnemonic::parse("illness spike retreat truth genius clock brain pass fit cave bargain toe").unwrap();
+        let (bytes, entropy) = (mnemonic.to_seed(""), mnemonic.to_entropy());
+        let seed = Seed { bytes: &bytes, entropy: &entropy };
+        let options = Options::default();
+
+        let accounts: Vec<(String, String)> = (0..2).map(|index| {
+            let path = expand_path(&Stellar.path_template(&options), 0, index);
+            let (secret, public) = Stellar.curve(&options).derive(&seed, &path).unwrap();
+
+            (Stellar.address(&public, &options).unwrap(), Stellar.private_key(&secret, &options).unwrap().to_string())
+        }).collect();
+
+        assert_eq!(accounts[0].0, "GDRXE2BQUC3AZNPVFSCEZ76NJ3WWL25FYFK6RGZGIEKWE4SOOHSUJUJ6");
+        assert_eq!(accounts[0].1, "SBGWSG6BTNCKCOB3DIFBGCVMUPQFYPA2G4O34RMTB343OYPXU5DJDVMN");
+        assert_eq!(accounts[1].0, "GBAW5XGWORWVFE2XTJYDTLDHXTY2Q2MO73HYCGB3XMFMQ562Q2W2GJQX");
+        assert_eq!(accounts[1].1, "SCEPFFWGAG5P2VX5DHIYK3XEMZYLTYWIPWYEKXFHSK25RVMIUNJ7CTIS");
+    }
+}
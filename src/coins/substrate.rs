@@ -0,0 +1,232 @@
+use std::error::Error;
+use bitcoin::base58;
+use bitcoin::hex::DisplayHex;
+use blake2::digest::consts::{U32, U64};
+use blake2::{Blake2b, Digest};
+use schnorrkel::derive::{ChainCode, Derivation};
+use schnorrkel::{ExpansionMode, MiniSecretKey};
+use sha2::Sha512;
+use zeroize::Zeroizing;
+use crate::coins::{Coin, Curve, Options};
+use crate::crypto::SecretBytes;
+
+/// SS58 prefixes above this take two bytes, 16383 is the last one
+const SIMPLE_PREFIX_LIMIT: u16 = 64;
+const MAX_PREFIX: u16 = 16383;
+
+/// A Substrate chain, addresses are the sr25519 public key in SS58 with the chain's prefix
+pub struct Substrate {
+    name: String,
+    coin_type: u32,
+    prefix: u16,
+    explorer: Option<String>,
+}
+
+impl Substrate {
+    pub fn new(name: &str, coin_type: u32, prefix: u16, explorer: Option<&str>) -> Result<Substrate, Box<dyn Error>> {
+        check_prefix(prefix)?;
+
+        Ok(Substrate { name: name.to_string(), coin_type, prefix, explorer: explorer.map(String::from) })
+    }
+
+    pub fn polkadot() -> Substrate {
+        Substrate { name: "dot".to_string(), coin_type: 354, prefix: 0, explorer: Some("https://polkadot.subscan.io/account/{address}".to_string()) }
+    }
+
+    pub fn kusama() -> Substrate {
+        Substrate { name: "ksm".to_string(), coin_type: 434, prefix: 2, explorer: Some("https://kusama.subscan.io/account/{address}".to_string()) }
+    }
+}
+
+impl Coin for Substrate {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn coin_type(&self) -> u32 {
+        self.coin_type
+    }
+
+    fn curve(&self, _options: &Options) -> Curve {
+        Curve::Sr25519
+    }
+
+    /// Junctions of `--substrate_path`, no junctions is the account polkadot.js creates from the mnemonic
+    fn path_template(&self, options: &Options) -> String {
+        options.substrate_path.clone()
+    }
+
+    fn address(&self, public_key: &[u8], options: &Options) -> Result<String, Box<dyn Error>> {
+        Ok(ss58(options.ss58_prefix.unwrap_or(self.prefix), public_key))
+    }
+
+    /// Secret seed of subkey and of the raw seed import of polkadot.js, a soft junction leaves no seed
+    fn private_key(&self, secret: &[u8], _options: &Options) -> Result<Zeroizing<String>, Box<dyn Error>> {
+        if secret.is_empty() {
+            return Ok(Zeroizing::new("none after a soft junction, import the mnemonic with the path".to_string()));
+        }
+
+        Ok(Zeroizing::new(format!("0x{}", Zeroizing::new(secret.to_lower_hex_string()).as_str())))
+    }
+
+    fn explorer(&self) -> Option<&str> {
+        self.explorer.as_deref()
+    }
+}
+
+pub fn check_prefix(prefix: u16) -> Result<(), Box<dyn Error>> {
+    // 46 and 47 are reserved
+    if prefix > MAX_PREFIX || prefix == 46 || prefix == 47 {
+        return Err(format!("Wrong SS58 prefix {}, expected 0..{} except 46 and 47", prefix, MAX_PREFIX).into());
+    }
+
+    Ok(())
+}
+
+/// One step of a derivation path
+pub struct Junction {
+    hard: bool,
+    chain_code: [u8; 32],
+}
+
+/// Mini-secret of substrate-bip39: PBKDF2-HMAC-SHA512 of the BIP39 entropy, not of the words, with the "mnemonic" salt
+fn mini_secret(entropy: &[u8]) -> Zeroizing<[u8; 32]> {
+    let mut output = Zeroizing::new([0u8; 64]);
+    pbkdf2::pbkdf2_hmac::<Sha512>(entropy, b"mnemonic", 2048, output.as_mut());
+
+    let mut secret = Zeroizing::new([0u8; 32]);
+    secret.copy_from_slice(&output[..32]);
+
+    secret
+}
+
+/// Secret seed and public key at the junctions, the secret seed is empty when the last junction is soft
+pub fn derive(entropy: &[u8], path: &str) -> Result<(SecretBytes, Vec<u8>), Box<dyn Error>> {
+    let mini = MiniSecretKey::from_bytes(mini_secret(entropy).as_ref()).map_err(|e| e.to_string())?;
+    let mut seed = Zeroizing::new(mini.to_bytes().to_vec());
+    let mut key = mini.expand(ExpansionMode::Ed25519);
+
+    for junction in junctions(path)? {
+        if junction.hard {
+            let (mini, _) = key.hard_derive_mini_secret_key(Some(ChainCode(junction.chain_code)), b"");
+            seed = Zeroizing::new(mini.to_bytes().to_vec());
+            key = mini.expand(ExpansionMode::Ed25519);
+        } else {
+            key = key.derived_key_simple(ChainCode(junction.chain_code), []).0;
+            seed = Zeroizing::new(vec![]);
+        }
+    }
+
+    Ok((seed, key.to_public().to_bytes().to_vec()))
+}
+
+/// `//hard` and `/soft` junctions with their chain codes: a number is its u64, anything else is a SCALE string,
+/// padded to 32 bytes or hashed with BLAKE2b-256 when longer
+pub fn junctions(path: &str) -> Result<Vec<Junction>, Box<dyn Error>> {
+    let wrong_path = || format!("Wrong substrate path {:?}, expected junctions like //hard/soft", path);
+
+    let mut result = vec![];
+    let mut rest = path;
+
+    while !rest.is_empty() {
+        rest = rest.strip_prefix('/').ok_or_else(wrong_path)?;
+
+        let hard = rest.starts_with('/');
+        if hard {
+            rest = &rest[1..];
+        }
+
+        let end = rest.find('/').unwrap_or(rest.len());
+        let code = &rest[..end];
+        rest = &rest[end..];
+
+        if code.is_empty() {
+            return Err(wrong_path().into());
+        }
+
+        let encoded = match code.parse::<u64>() {
+            Ok(number) => number.to_le_bytes().to_vec(),
+            Err(_) => {
+                let mut encoded = compact_length(code.len());
+                encoded.extend_from_slice(code.as_bytes());
+                encoded
+            }
+        };
+
+        let mut chain_code = [0u8; 32];
+
+        if encoded.len() > 32 {
+            chain_code.copy_from_slice(&Blake2b::<U32>::digest(&encoded));
+        } else {
+            chain_code[..encoded.len()].copy_from_slice(&encoded);
+        }
+
+        result.push(Junction { hard, chain_code });
+    }
+
+    Ok(result)
+}
+
+/// SCALE compact encoding of a length
+fn compact_length(length: usize) -> Vec<u8> {
+    match length {
+        0..=0x3f => vec![(length as u8) << 2],
+        0x40..=0x3fff => (((length as u16) << 2) | 0b01).to_le_bytes().to_vec(),
+        _ => (((length as u32) << 2) | 0b10).to_le_bytes().to_vec(),
+    }
+}
+
+/// Prefix, public key and the first 2 bytes of BLAKE2b-512 of "SS58PRE" and both, in base58
+pub fn ss58(prefix: u16, public_key: &[u8]) -> String {
+    let mut data = if prefix < SIMPLE_PREFIX_LIMIT {
+        vec![prefix as u8]
+    } else {
+        vec![((prefix & 0b1111_1100) >> 2) as u8 | 0b0100_0000, ((prefix >> 8) as u8) | (((prefix & 0b11) as u8) << 6)]
+    };
+
+    data.extend_from_slice(public_key);
+
+    let mut hash = Blake2b::<U64>::new();
+    hash.update(b"SS58PRE");
+    hash.update(&data);
+    data.extend_from_slice(&hash.finalize()[..2]);
+
+    base58::encode(&data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bip39::Mnemonic;
+
+    /// Development phrase of Substrate, its accounts are in polkadot.js and subkey
+    const DEV_PHRASE: &str = "bottom drive obey lake curtain smoke basket hold race lonely fit walk";
+
+    fn account(path: &str) -> (SecretBytes, Vec<u8>) {
+        derive(&Mnemonic::parse(DEV_PHRASE).unwrap().to_entropy(), path).unwrap()
+    }
+
+    #[test]
+    fn root_account() {
+        assert_eq!(ss58(42, &account("").1), "5DfhGyQdFobKM8NsWvEeAKk5EQQgYe9AydgJ7rMB6E1EqRzV");
+    }
+
+    #[test]
+    fn hard_junction() {
+        let (secret, public) = account("//Alice");
+
+        assert_eq!(secret.to_lower_hex_string(), "e5be9a5092b81bca64be81d212e7f2f9eba183bb7a90954f7b76361f6edb5c0a");
+        assert_eq!(public.to_lower_hex_string(), "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d");
+        assert_eq!(ss58(42, &public), "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY");
+    }
+
+    #[test]
+    fn soft_junction_has_no_seed() {
+        assert!(account("//Alice/0").0.is_empty());
+    }
+
+    #[test]
+    fn kusama_prefix() {
+        assert_eq!(ss58(2, &account("//Alice").1), "HNZata7iMYWmk5RvZRTiAsSDhV8366zq2YGb3tLH5Upf74F");
+    }
+}
@@ -0,0 +1,172 @@
+use std::error::Error;
+use bitcoin::bech32::{self, Bech32, Hrp};
+use bitcoin::hex::DisplayHex;
+use blake2::digest::consts::U32;
+use blake2::{Blake2b, Digest};
+use zeroize::Zeroizing;
+use crate::coins::{Coin, Curve, Options};
+
+/// SLIP-44 coin type of Sui
+pub const COIN_TYPE: u32 = 784;
+
+/// Signature schemes of Sui accounts, each has its own purpose in the path and flag byte in the address
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Scheme {
+    /// `m/44'/784'/i'/0'/0'` SLIP-10, Sui Wallet and Atomic
+    Ed25519,
+    /// `m/54'/784'/i'/0/0` BIP32
+    Secp256k1,
+    /// `m/74'/784'/i'/0/0`, the secret comes from secp256k1 BIP32 as in the Sui SDKs and is used as a P-256 key
+    Secp256r1,
+}
+
+impl Scheme {
+    pub fn parse(name: &str) -> Result<Scheme, Box<dyn Error>> {
+        match name.to_lowercase().as_str() {
+            "ed25519" => Ok(Scheme::Ed25519),
+            "secp256k1" | "k1" => Ok(Scheme::Secp256k1),
+            "secp256r1" | "r1" => Ok(Scheme::Secp256r1),
+            _ => Err(format!("Wrong sui scheme {:?}, expected ed25519, secp256k1 or secp256r1", name).into()),
+        }
+    }
+
+    fn flag(self) -> u8 {
+        match self {
+            Scheme::Ed25519 => 0x00,
+            Scheme::Secp256k1 => 0x01,
+            Scheme::Secp256r1 => 0x02,
+        }
+    }
+
+    /// The account index is `{index}`, Sui wallets keep the account level at 0
+    fn template(self) -> String {
+        match self {
+            Scheme::Ed25519 => format!("m/44'/{}'/{{index}}'/0'/0'", COIN_TYPE),
+            Scheme::Secp256k1 => format!("m/54'/{}'/{{index}}'/0/0", COIN_TYPE),
+            Scheme::Secp256r1 => format!("m/74'/{}'/{{index}}'/0/0", COIN_TYPE),
+        }
+    }
+
+    fn curve(self) -> Curve {
+        match self {
+            Scheme::Ed25519 => Curve::Ed25519,
+            Scheme::Secp256k1 => Curve::Secp256k1,
+            Scheme::Secp256r1 => Curve::Secp256r1,
+        }
+    }
+}
+
+/// BLAKE2b-256 of the flag byte and the public key
+pub fn address(scheme: Scheme, public_key: &[u8]) -> String {
+    let mut hash = Blake2b::<U32>::new();
+    hash.update([scheme.flag()]);
+    hash.update(public_key);
+
+    format!("0x{}", hash.finalize().to_lower_hex_string())
+}
+
+/// Bech32 `suiprivkey1…` of the flag byte and the secret key, the format of `sui keytool` and Sui Wallet
+fn private_key(scheme: Scheme, secret: &[u8]) -> Result<Zeroizing<String>, Box<dyn Error>> {
+    let mut data = Zeroizing::new(vec![scheme.flag()]);
+    data.extend_from_slice(secret);
+
+    Ok(Zeroizing::new(bech32::encode::<Bech32>(Hrp::parse("suiprivkey")?, &data)?))
+}
+
+/// Accounts of the scheme selected with `--sui_scheme`
+pub struct Sui;
+
+impl Coin for Sui {
+    fn name(&self) -> &str {
+        "sui"
+    }
+
+    fn coin_type(&self) -> u32 {
+        COIN_TYPE
+    }
+
+    fn curve(&self, options: &Options) -> Curve {
+        options.sui_scheme.curve()
+    }
+
+    fn path_template(&self, options: &Options) -> String {
+        options.sui_scheme.template()
+    }
+
+    fn address(&self, public_key: &[u8], options: &Options) -> Result<String, Box<dyn Error>> {
+        Ok(address(options.sui_scheme, public_key))
+    }
+
+    fn private_key(&self, secret: &[u8], options: &Options) -> Result<Zeroizing<String>, Box<dyn Error>> {
+        private_key(options.sui_scheme, secret)
+    }
+
+    fn explorer(&self) -> Option<&str> {
+        Some("https://suivision.xyz/account/{address}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bip39::Mnemonic;
+    use crate::coins::{expand_path, Seed};
+    use crate::crypto::SecretBytes;
+
+    /// Mnemonics of the keypair tests of the Sui TypeScript SDK
+    const MNEMONICS: [&str; 3] = [
+        "film crazy soon outside stand loop subway crumble thrive popular green nuclear struggle pistol arm wife phrase warfare march wheat nephew ask sunny firm",
+        "require decline left thought grid priority false tiny gasp angle royal system attack beef setup reward aunt skill wasp tray vital bounce inflict level",
+        "organ crash swim stick traffic remember army arctic mesh slice swear summer police vast chaos cradle squirrel hood useless evidence pet hub soap lake",
+    ];
+
+    fn account(mnemonic: &str, scheme: Scheme) -> (SecretBytes, Vec<u8>) {
+        let mnemonic = Mnemonic::parse(mnemonic).unwrap();
+        let (bytes, entropy) = (mnemonic.to_seed(""), mnemonic.to_entropy());
+        let seed = Seed { bytes: &bytes, entropy: &entropy };
+
+        scheme.curve().derive(&seed, &expand_path(&scheme.template(), 0, 0)).unwrap()
+    }
+
+    fn addresses(scheme: Scheme) -> Vec<String> {
+        MNEMONICS.iter().map(|mnemonic| address(scheme, &account(mnemonic, scheme).1)).collect()
+    }
+
+    #[test]
+    fn ed25519_vectors() {
+        assert_eq!(addresses(Scheme::Ed25519), [
+            "0xa2d14fad60c56049ecf75246a481934691214ce413e6a8ae2fe6834c173a6133",
+            "0x1ada6e6f3f3e4055096f606c746690f1108fcc2ca479055cc434a3e1d3f758aa",
+            "0xe69e896ca10f5a77732769803cc2b5707f0ab9d4407afb5e4b4464b89769af14",
+        ]);
+    }
+
+    #[test]
+    fn secp256k1_vectors() {
+        assert_eq!(addresses(Scheme::Secp256k1), [
+            "0x9e8f732575cc5386f8df3c784cd3ed1b53ce538da79926b2ad54dcc1197d2532",
+            "0x9fd5a804ed6b46d36949ff7434247f0fd594673973ece24aede6b86a7b5dae01",
+            "0x60287d7c38dee783c2ab1077216124011774be6b0764d62bd05f32c88979d5c5",
+        ]);
+    }
+
+    /// Secp256r1 keypair test of the Sui TypeScript SDK, the secp256k1 BIP32 secret of m/74'/784'/0'/0/0 as a P-256 key
+    #[test]
+    fn secp256r1_vector() {
+        let (_, public) = account("act wing dilemma glory episode region allow mad tourist humble muffin oblige", Scheme::Secp256r1);
+
+        assert_eq!(address(Scheme::Secp256r1, &public), "0x4a822457f1970468d38dae8e63fb60eefdaa497d74d781f581ea2d137ec36f3a");
+    }
+
+    #[test]
+    fn suiprivkey_encoding() {
+        let (secret, _) = account(MNEMONICS[0], Scheme::Ed25519);
+        let encoded = private_key(Scheme::Ed25519, &secret).unwrap();
+        let (hrp, data) = bech32::decode(&encoded).unwrap();
+
+        assert_eq!(encoded.as_str(), "suiprivkey1qrwsjvr6gwaxmsvxk4cfun99ra8uwxg3c9pl0nhle7xxpe4s80y05ctazer");
+        assert_eq!(hrp.as_str(), "suiprivkey");
+        assert_eq!(data[0], Scheme::Ed25519.flag());
+        assert_eq!(&data[1..], secret.as_slice());
+    }
+}
@@ -0,0 +1,273 @@
+use std::error::Error;
+use base64::engine::general_purpose::URL_SAFE;
+use base64::Engine;
+use bitcoin::hex::{DisplayHex, FromHex};
+use bitcoin::NetworkKind;
+use ed25519_dalek::SigningKey;
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+use crate::coins::{expand_path, Coin, Curve, Options, Output, Seed};
+
+/// SLIP-44 coin type of TON
+pub const COIN_TYPE: u32 = 607;
+
+/// Tags of user-friendly addresses, testnet addresses have the high bit set
+const BOUNCEABLE: u8 = 0x11;
+const NON_BOUNCEABLE: u8 = 0x51;
+const TESTNET: u8 = 0x80;
+
+/// Default subwallet id of wallet v3 and v4 contracts in the basechain
+const SUBWALLET_ID: u32 = 698983191;
+
+/// Global ids of the networks, they are part of the wallet id of v5 contracts
+const MAINNET_ID: i32 = -239;
+const TESTNET_ID: i32 = -3;
+
+/// Wallet contracts of Tonkeeper, Tonhub and MyTonWallet
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Contract {
+    V4R2,
+    /// W5, the default of new Tonkeeper wallets
+    V5R1,
+}
+
+impl Contract {
+    pub const ALL: [Contract; 2] = [Contract::V4R2, Contract::V5R1];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Contract::V4R2 => "v4R2",
+            Contract::V5R1 => "v5R1",
+        }
+    }
+
+    /// Representation hash and depth of the code cell of the contract
+    fn code(self) -> (&'static str, u16) {
+        match self {
+            Contract::V4R2 => ("feb5ff6820e2ff0d9483e7e0d62c817d846789fb4ae580c878866d959dabd5c0", 7),
+            Contract::V5R1 => ("20834b7b72b112147e1b2fb457b84e74d1a30f04f737d4f62a668e9552d2b72f", 6),
+        }
+    }
+
+    /// Initial data of a new wallet: v4 has seqno, subwallet id, public key and an empty plugin dictionary,
+    /// v5 has the signature flag, seqno, wallet id, public key and an empty extension dictionary
+    fn data(self, public_key: &[u8], network: NetworkKind) -> Cell {
+        let mut cell = Cell::default();
+
+        match self {
+            Contract::V4R2 => {
+                cell.push(0, 32);
+                cell.push(SUBWALLET_ID as u64, 32);
+                cell.push_bytes(public_key);
+                cell.push(0, 1);
+            }
+            Contract::V5R1 => {
+                // The client context of the basechain and subwallet 0 is the top bit alone
+                let global_id = match network {
+                    NetworkKind::Main => MAINNET_ID,
+                    NetworkKind::Test => TESTNET_ID,
+                };
+                let wallet_id = global_id as u32 ^ 0x8000_0000;
+
+                cell.push(1, 1);
+                cell.push(0, 32);
+                cell.push(wallet_id as u64, 32);
+                cell.push_bytes(public_key);
+                cell.push(0, 1);
+            }
+        }
+
+        cell
+    }
+
+    /// Hash of the StateInit with the code and the data, it is the account id in the basechain
+    fn account_id(self, public_key: &[u8], network: NetworkKind) -> [u8; 32] {
+        let (code_hash, code_depth) = self.code();
+        let data = self.data(public_key, network);
+
+        // No split depth and no special, code and data are present, no libraries
+        let mut hash = Sha256::new();
+        hash.update([2, 1, 0b0011_0100]);
+        hash.update(code_depth.to_be_bytes());
+        hash.update(0u16.to_be_bytes());
+        hash.update(<[u8; 32]>::from_hex(code_hash).unwrap());
+        hash.update(data.hash());
+
+        hash.finalize().into()
+    }
+}
+
+/// Data bits of a cell without references
+#[derive(Default)]
+struct Cell {
+    bits: Vec<bool>,
+}
+
+impl Cell {
+    fn push(&mut self, value: u64, count: usize) {
+        self.bits.extend((0..count).rev().map(|bit| (value >> bit) & 1 == 1));
+    }
+
+    fn push_bytes(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.push(byte as u64, 8);
+        }
+    }
+
+    /// Representation hash: the descriptors, then the bits with a 1 and zeros up to a whole byte when it's incomplete
+    fn hash(&self) -> [u8; 32] {
+        let length = self.bits.len();
+        let mut data = vec![0u8; length.div_ceil(8)];
+
+        for (n, _) in self.bits.iter().enumerate().filter(|(_, &bit)| bit) {
+            data[n / 8] |= 0x80 >> (n % 8);
+        }
+
+        if !length.is_multiple_of(8) {
+            data[length / 8] |= 0x80 >> (length % 8);
+        }
+
+        let mut hash = Sha256::new();
+        hash.update([0, (length / 8 + length.div_ceil(8)) as u8]);
+        hash.update(&data);
+
+        hash.finalize().into()
+    }
+}
+
+/// User-friendly address: tag, workchain 0, account id and CRC16-XModem of them, in url-safe base64
+pub fn user_friendly(account_id: &[u8; 32], bounceable: bool, network: NetworkKind) -> String {
+    let mut tag = if bounceable { BOUNCEABLE } else { NON_BOUNCEABLE };
+
+    if network == NetworkKind::Test {
+        tag |= TESTNET;
+    }
+
+    let mut data = vec![tag, 0];
+    data.extend_from_slice(account_id);
+    data.extend_from_slice(&crc16(&data).to_be_bytes());
+
+    URL_SAFE.encode(&data)
+}
+
+/// CRC16-XModem, polynomial 0x1021 and no final xor
+fn crc16(data: &[u8]) -> u16 {
+    data.iter().fold(0u16, |crc, &byte| {
+        (0..8).fold(crc ^ ((byte as u16) << 8), |crc, _| if crc & 0x8000 != 0 { (crc << 1) ^ 0x1021 } else { crc << 1 })
+    })
+}
+
+/// Wallet v4R2 and v5R1 contracts of ed25519 keys, non-bounceable `UQ…` addresses as wallets show them,
+/// BIP39 words get SLIP-10 keys of `m/44'/607'/i'` as in Trust Wallet and Tonkeeper's BIP39 import
+pub struct Ton;
+
+impl Ton {
+    /// Addresses of both contracts of one key, then the private key
+    pub fn print_contracts(&self, path: &str, secret: &[u8], public_key: &[u8], out: &Output, options: &Options) -> Result<(), Box<dyn Error>> {
+        for contract in Contract::ALL {
+            let account_id = contract.account_id(public_key, options.network_kind());
+            let label = format!("{} {}", self.name(), contract.name());
+
+            out.address(self, &label, path, &user_friendly(&account_id, false, options.network_kind()));
+        }
+
+        if out.show.has("privkey") {
+            out.private_key(&self.private_key(secret, options)?);
+        }
+
+        Ok(())
+    }
+}
+
+impl Coin for Ton {
+    fn name(&self) -> &str {
+        "ton"
+    }
+
+    fn coin_type(&self) -> u32 {
+        COIN_TYPE
+    }
+
+    fn curve(&self, _options: &Options) -> Curve {
+        Curve::Ed25519
+    }
+
+    fn path_template(&self, _options: &Options) -> String {
+        format!("m/44'/{}'/{{index}}'", COIN_TYPE)
+    }
+
+    /// Wallet v4R2 address
+    fn address(&self, public_key: &[u8], options: &Options) -> Result<String, Box<dyn Error>> {
+        let account_id = Contract::V4R2.account_id(public_key, options.network_kind());
+
+        Ok(user_friendly(&account_id, false, options.network_kind()))
+    }
+
+    /// Hex of the 64-byte secret key of ton-crypto and tonweb, the ed25519 seed and the public key
+    fn private_key(&self, secret: &[u8], _options: &Options) -> Result<Zeroizing<String>, Box<dyn Error>> {
+        let key = SigningKey::from_bytes(secret.try_into()?);
+        let keypair = Zeroizing::new(key.to_keypair_bytes());
+
+        Ok(Zeroizing::new(keypair.to_lower_hex_string()))
+    }
+
+    fn explorer(&self) -> Option<&str> {
+        Some("https://tonviewer.com/{address}")
+    }
+
+    fn print_addresses(&self, seed: &Seed, out: &Output, options: &Options) -> Result<(), Box<dyn Error>> {
+        for index in 0..options.gap {
+            let path = expand_path(&self.path_template(options), options.account, index);
+            let (secret, public) = self.curve(options).derive(seed, &path)?;
+
+            self.print_contracts(&path, &secret, &public, out, options)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Public key of the private key 63474e5f…9cb8 of wallet-core's TON tests, its v4R2 address is wallet-core's vector,
+    /// the v5R1 code hash and depth were checked by rebuilding the W5 code cell
+    const PUBLIC_KEY: &str = "f42c77f931bea20ec5d0150731276bbb2e2860947661245b2319ef8133ee8d41";
+
+    fn address(contract: Contract, network: NetworkKind) -> String {
+        let public_key = <[u8; 32]>::from_hex(PUBLIC_KEY).unwrap();
+
+        user_friendly(&contract.account_id(&public_key, network), false, network)
+    }
+
+    #[test]
+    fn v4r2_addresses() {
+        assert_eq!(address(Contract::V4R2, NetworkKind::Main), "UQBm--PFwDv1yCeS-QTJ-L8oiUpqo9IT1BwgVptlSq3ts4DV");
+        assert_eq!(address(Contract::V4R2, NetworkKind::Test), "0QBm--PFwDv1yCeS-QTJ-L8oiUpqo9IT1BwgVptlSq3tsztf");
+    }
+
+    #[test]
+    fn v5r1_addresses() {
+        assert_eq!(address(Contract::V5R1, NetworkKind::Main), "UQB-dct4cPDNBe6rQyI-yjKqmcdUggX_wHm_mqjd0cvL7gbK");
+        assert_eq!(address(Contract::V5R1, NetworkKind::Test), "0QCgBFb8JwnuQ9fT4_mEqzGzwIto3jx7vD00qEl0HKDdSYk2");
+    }
+
+    #[test]
+    fn bounceable_address() {
+        let public_key = <[u8; 32]>::from_hex(PUBLIC_KEY).unwrap();
+        let account_id = Contract::V4R2.account_id(&public_key, NetworkKind::Main);
+
+        assert_eq!(user_friendly(&account_id, true, NetworkKind::Main), "EQBm--PFwDv1yCeS-QTJ-L8oiUpqo9IT1BwgVptlSq3ts90Q");
+    }
+
+    #[test]
+    fn empty_cell_hash() {
+        assert_eq!(Cell::default().hash().to_lower_hex_string(), "96a296d224f285c67bee93c30f8a309157f0daa35dc5b87e410b78630a09cfc7");
+    }
+
+    #[test]
+    fn crc16_check_value() {
+        assert_eq!(crc16(b"123456789"), 0x31c3);
+    }
+}
@@ -0,0 +1,73 @@
+use std::error::Error;
+use bitcoin::base58;
+use bitcoin::hex::DisplayHex;
+use bitcoin::secp256k1::PublicKey;
+use sha3::{Digest, Keccak256};
+use zeroize::Zeroizing;
+use crate::coins::{Coin, Curve, Options};
+
+/// SLIP-44 coin type of Tron
+pub const COIN_TYPE: u32 = 195;
+
+/// Version byte of Tron addresses, gives the `T` of base58
+const ADDRESS_PREFIX: u8 = 0x41;
+
+/// Base58check `T…` addresses of `m/44'/195'/account'/0/i` as in TronLink, Trust Wallet and Ledger
+pub struct Tron;
+
+impl Coin for Tron {
+    fn name(&self) -> &str {
+        "trx"
+    }
+
+    fn coin_type(&self) -> u32 {
+        COIN_TYPE
+    }
+
+    fn curve(&self, _options: &Options) -> Curve {
+        Curve::Secp256k1
+    }
+
+    fn path_template(&self, _options: &Options) -> String {
+        format!("m/44'/{}'/{{account}}'/0/{{index}}", COIN_TYPE)
+    }
+
+    /// 0x41 and the last 20 bytes of Keccak-256 of the uncompressed public key, the Ethereum address hash
+    fn address(&self, public_key: &[u8], _options: &Options) -> Result<String, Box<dyn Error>> {
+        let public_key = PublicKey::from_slice(public_key)?;
+        let hash = Keccak256::digest(&public_key.serialize_uncompressed()[1..]);
+
+        let mut data = vec![ADDRESS_PREFIX];
+        data.extend_from_slice(&hash[12..]);
+
+        Ok(base58::encode_check(&data))
+    }
+
+    /// Hex of the secret key without `0x`, TronLink imports it as is
+    fn private_key(&self, secret: &[u8], _options: &Options) -> Result<Zeroizing<String>, Box<dyn Error>> {
+        Ok(Zeroizing::new(secret.to_lower_hex_string()))
+    }
+
+    fn explorer(&self) -> Option<&str> {
+        Some("https://tronscan.org/#/address/{address}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bip39::Mnemonic;
+    use crate::coins::{expand_path, Seed};
+
+    #[test]
+    fn tronlink_vector() {
+        let mnemonic = Mnemonic::parse("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about").unwrap();
+        let (bytes, entropy) = (mnemonic.to_seed(""), mnemonic.to_entropy());
+        let seed = Seed { bytes: &bytes, entropy: &entropy };
+        let options = Options::default();
+
+        let (_, public) = Tron.curve(&options).derive(&seed, &expand_path(&Tron.path_template(&options), 0, 0)).unwrap();
+
+        assert_eq!(Tron.address(&public, &options).unwrap(), "TUEZSdKsoDHQMeZwihtdoBiN46zxhGWYdH");
+    }
+}
@@ -0,0 +1,378 @@
+use std::error::Error;
+use bitcoin::base58;
+use bitcoin::bech32::{segwit, Hrp};
+use bitcoin::bip32::{ChildNumber, Xpriv, Xpub};
+use bitcoin::hashes::{hash160, Hash};
+use bitcoin::secp256k1::{PublicKey, Secp256k1};
+use bitcoin::{Network, WitnessProgram};
+use zeroize::Zeroizing;
+use crate::coins::{Coin, Curve, Options, Output, Seed};
+use crate::keys::{account_path, root_key};
+
+/// Script types with the BIP that defines their derivation path
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum AddressType {
+    /// BIP44 legacy `1…`
+    P2pkh,
+    /// BIP49 nested SegWit `3…`
+    P2shP2wpkh,
+    /// BIP84 native SegWit `bc1q…`
+    P2wpkh,
+    /// BIP86 Taproot `bc1p…`
+    P2tr,
+}
+
+impl AddressType {
+    pub fn parse(name: &str) -> Result<AddressType, Box<dyn Error>> {
+        match name.trim().to_lowercase().as_str() {
+            "bip44" | "p2pkh" | "legacy" => Ok(AddressType::P2pkh),
+            "bip49" | "p2sh-p2wpkh" | "nested" => Ok(AddressType::P2shP2wpkh),
+            "bip84" | "p2wpkh" | "segwit" => Ok(AddressType::P2wpkh),
+            "bip86" | "p2tr" | "taproot" => Ok(AddressType::P2tr),
+            _ => Err(format!("Wrong address type {:?}, expected bip44, bip49, bip84 or bip86", name).into()),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            AddressType::P2pkh => "bip44",
+            AddressType::P2shP2wpkh => "bip49",
+            AddressType::P2wpkh => "bip84",
+            AddressType::P2tr => "bip86",
+        }
+    }
+
+    pub fn purpose(self) -> u32 {
+        match self {
+            AddressType::P2pkh => 44,
+            AddressType::P2shP2wpkh => 49,
+            AddressType::P2wpkh => 84,
+            AddressType::P2tr => 86,
+        }
+    }
+}
+
+/// Address version bytes of one network of a chain
+pub struct Params {
+    p2pkh: u8,
+    p2sh: u8,
+    wif: u8,
+    /// Prefix of SegWit addresses, None when the chain has no SegWit
+    hrp: Option<&'static str>,
+    /// Prefix of CashAddr addresses, they replace base58 P2PKH addresses
+    cashaddr: Option<&'static str>,
+}
+
+/// A Bitcoin-like chain
+pub struct Chain {
+    pub name: &'static str,
+    /// SLIP-44 coin type
+    pub coin_type: u32,
+    pub taproot: bool,
+    pub default_type: AddressType,
+    explorer: &'static str,
+    main: Params,
+    test: Params,
+    regtest: Params,
+}
+
+pub const BITCOIN: Chain = Chain {
+    name: "btc",
+    coin_type: 0,
+    taproot: true,
+    default_type: AddressType::P2wpkh,
+    explorer: "https://www.blockchain.com/explorer/addresses/btc/{address}",
+    main: Params { p2pkh: 0x00, p2sh: 0x05, wif: 0x80, hrp: Some("bc"), cashaddr: None },
+    test: Params { p2pkh: 0x6f, p2sh: 0xc4, wif: 0xef, hrp: Some("tb"), cashaddr: None },
+    regtest: Params { p2pkh: 0x6f, p2sh: 0xc4, wif: 0xef, hrp: Some("bcrt"), cashaddr: None },
+};
+
+pub const LITECOIN: Chain = Chain {
+    name: "ltc",
+    coin_type: 2,
+    taproot: true,
+    default_type: AddressType::P2wpkh,
+    explorer: "https://blockchair.com/litecoin/address/{address}",
+    main: Params { p2pkh: 0x30, p2sh: 0x32, wif: 0xb0, hrp: Some("ltc"), cashaddr: None },
+    test: Params { p2pkh: 0x6f, p2sh: 0x3a, wif: 0xef, hrp: Some("tltc"), cashaddr: None },
+    regtest: Params { p2pkh: 0x6f, p2sh: 0x3a, wif: 0xef, hrp: Some("rltc"), cashaddr: None },
+};
+
+pub const DOGECOIN: Chain = Chain {
+    name: "doge",
+    coin_type: 3,
+    taproot: false,
+    default_type: AddressType::P2pkh,
+    explorer: "https://dogechain.info/address/{address}",
+    main: Params { p2pkh: 0x1e, p2sh: 0x16, wif: 0x9e, hrp: None, cashaddr: None },
+    test: Params { p2pkh: 0x71, p2sh: 0xc4, wif: 0xf1, hrp: None, cashaddr: None },
+    regtest: Params { p2pkh: 0x6f, p2sh: 0xc4, wif: 0xef, hrp: None, cashaddr: None },
+};
+
+pub const BITCOIN_CASH: Chain = Chain {
+    name: "bch",
+    coin_type: 145,
+    taproot: false,
+    default_type: AddressType::P2pkh,
+    explorer: "https://blockchair.com/bitcoin-cash/address/{address}",
+    main: Params { p2pkh: 0x00, p2sh: 0x05, wif: 0x80, hrp: None, cashaddr: Some("bitcoincash") },
+    test: Params { p2pkh: 0x6f, p2sh: 0xc4, wif: 0xef, hrp: None, cashaddr: Some("bchtest") },
+    regtest: Params { p2pkh: 0x6f, p2sh: 0xc4, wif: 0xef, hrp: None, cashaddr: Some("bchreg") },
+};
+
+impl Chain {
+    fn params(&self, network: Network) -> &Params {
+        match network {
+            Network::Bitcoin => &self.main,
+            Network::Regtest => &self.regtest,
+            _ => &self.test,
+        }
+    }
+
+    fn supports(&self, address_type: AddressType, params: &Params) -> bool {
+        match address_type {
+            AddressType::P2pkh => true,
+            AddressType::P2shP2wpkh | AddressType::P2wpkh => params.hrp.is_some(),
+            AddressType::P2tr => params.hrp.is_some() && self.taproot,
+        }
+    }
+
+    fn address_of(&self, address_type: AddressType, key: &PublicKey, params: &Params) -> Result<String, Box<dyn Error>> {
+        let secp = Secp256k1::verification_only();
+        let key_hash = hash160::Hash::hash(&key.serialize());
+
+        match address_type {
+            AddressType::P2pkh => match params.cashaddr {
+                Some(prefix) => Ok(cashaddr(prefix, key_hash.as_byte_array())),
+                None => Ok(base58_address(params.p2pkh, key_hash.as_byte_array())),
+            },
+            AddressType::P2shP2wpkh => {
+                let mut redeem_script = vec![0x00, 0x14];
+                redeem_script.extend_from_slice(key_hash.as_byte_array());
+
+                Ok(base58_address(params.p2sh, hash160::Hash::hash(&redeem_script).as_byte_array()))
+            }
+            AddressType::P2wpkh => {
+                Ok(segwit::encode_v0(self.hrp(params)?, key_hash.as_byte_array())?)
+            }
+            AddressType::P2tr => {
+                let program = WitnessProgram::p2tr(&secp, key.x_only_public_key().0, None);
+
+                Ok(segwit::encode_v1(self.hrp(params)?, program.program().as_bytes())?)
+            }
+        }
+    }
+
+    fn hrp(&self, params: &Params) -> Result<Hrp, Box<dyn Error>> {
+        let hrp = params.hrp.ok_or_else(|| format!("{} has no SegWit addresses", self.name))?;
+
+        Ok(Hrp::parse(hrp)?)
+    }
+
+    /// Wallet import format: version byte, secret key and 0x01 for a compressed public key
+    fn wif(&self, secret: &[u8; 32], params: &Params) -> Zeroizing<String> {
+        let mut data = Zeroizing::new(vec![params.wif]);
+        data.extend_from_slice(secret);
+        data.push(0x01);
+
+        Zeroizing::new(base58::encode_check(&data))
+    }
+}
+
+impl Coin for Chain {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn coin_type(&self) -> u32 {
+        self.coin_type
+    }
+
+    fn curve(&self, _options: &Options) -> Curve {
+        Curve::Secp256k1
+    }
+
+    /// Receive addresses of the default address type
+    fn path_template(&self, options: &Options) -> String {
+        format!("m/{}'/{}'/{{account}}'/0/{{index}}", self.default_type.purpose(), options.coin_type(self.coin_type))
+    }
+
+    fn address(&self, public_key: &[u8], options: &Options) -> Result<String, Box<dyn Error>> {
+        self.address_of(self.default_type, &PublicKey::from_slice(public_key)?, self.params(options.network))
+    }
+
+    fn private_key(&self, secret: &[u8], options: &Options) -> Result<Zeroizing<String>, Box<dyn Error>> {
+        let secret: &[u8; 32] = secret.try_into()?;
+
+        Ok(self.wif(secret, self.params(options.network)))
+    }
+
+    fn explorer(&self) -> Option<&str> {
+        Some(self.explorer)
+    }
+
+    /// Receive (chain 0) and change (chain 1) addresses of every selected address type the chain supports
+    fn print_addresses(&self, seed: &Seed, out: &Output, options: &Options) -> Result<(), Box<dyn Error>> {
+        let secp = Secp256k1::new();
+        let root: Xpriv = root_key(seed.bytes, options.network_kind())?;
+        let params = self.params(options.network);
+        let address_types = options.address_types.clone().unwrap_or_else(|| vec![self.default_type]);
+
+        for address_type in address_types {
+            let label = format!("{} {}", self.name, address_type.name());
+
+            if !self.supports(address_type, params) {
+                println!("    {}: not supported", label);
+                continue;
+            }
+
+            let path = account_path(address_type.purpose(), options.coin_type(self.coin_type), options.account)?;
+            let account = root.derive_priv(&secp, &path)?;
+            let account_xpub = Xpub::from_priv(&secp, &account);
+
+            for change in 0..2 {
+                for index in 0..options.gap {
+                    let child = [ChildNumber::from_normal_idx(change)?, ChildNumber::from_normal_idx(index)?];
+                    let key = account_xpub.derive_pub(&secp, &child)?.public_key;
+
+                    out.address(self, &label, &format!("m/{}/{}/{}", path, change, index), &self.address_of(address_type, &key, params)?);
+
+                    if out.show.has("privkey") {
+                        let secret = Zeroizing::new(account.derive_priv(&secp, &child)?.private_key.secret_bytes());
+                        out.private_key(self.wif(&secret, params).as_str());
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub fn parse_network(name: &str) -> Result<Network, Box<dyn Error>> {
+    match name.to_lowercase().as_str() {
+        "main" | "mainnet" | "bitcoin" => Ok(Network::Bitcoin),
+        "test" | "testnet" => Ok(Network::Testnet),
+        "signet" => Ok(Network::Signet),
+        "regtest" => Ok(Network::Regtest),
+        _ => Err(format!("Wrong network {:?}, expected main, testnet, signet or regtest", name).into()),
+    }
+}
+
+fn base58_address(version: u8, hash: &[u8]) -> String {
+    let mut data = vec![version];
+    data.extend_from_slice(hash);
+
+    base58::encode_check(&data)
+}
+
+/// CashAddr of a P2PKH key hash, `prefix:` and base32 of the version byte, the hash and a 40-bit BCH checksum
+fn cashaddr(prefix: &str, hash: &[u8]) -> String {
+    const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+    // Version 0 is P2PKH with a 160-bit hash
+    let mut payload = vec![0u8];
+    payload.extend_from_slice(hash);
+
+    let mut data: Vec<u8> = vec![];
+    let mut acc: u32 = 0;
+    let mut bits = 0;
+
+    for byte in payload {
+        acc = (acc << 8) | byte as u32;
+        bits += 8;
+
+        while bits >= 5 {
+            bits -= 5;
+            data.push(((acc >> bits) & 0x1f) as u8);
+        }
+    }
+
+    if bits > 0 {
+        data.push(((acc << (5 - bits)) & 0x1f) as u8);
+    }
+
+    let mut checked: Vec<u8> = prefix.bytes().map(|b| b & 0x1f).collect();
+    checked.push(0);
+    checked.extend(&data);
+    checked.extend([0u8; 8]);
+
+    let checksum = cashaddr_polymod(&checked);
+    data.extend((0..8).map(|i| ((checksum >> (5 * (7 - i))) & 0x1f) as u8));
+
+    let encoded: String = data.iter().map(|&d| CHARSET[d as usize] as char).collect();
+
+    format!("{}:{}", prefix, encoded)
+}
+
+fn cashaddr_polymod(values: &[u8]) -> u64 {
+    const GENERATORS: [u64; 5] = [0x98f2bc8e61, 0x79b76d99e2, 0xf33e5fb3c4, 0xae2eabe2a8, 0x1e4f43e470];
+
+    let mut c: u64 = 1;
+
+    for &value in values {
+        let c0 = c >> 35;
+        c = ((c & 0x07_ffff_ffff) << 5) ^ value as u64;
+
+        for (i, generator) in GENERATORS.iter().enumerate() {
+            if (c0 >> i) & 1 == 1 {
+                c ^= generator;
+            }
+        }
+    }
+
+    c ^ 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bip39::Mnemonic;
+    use bitcoin::NetworkKind;
+    use crate::coins::parse_path;
+    use crate::keys::{account_xpub, XpubFormat};
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    fn root() -> Xpriv {
+        root_key(&Mnemonic::parse(MNEMONIC).unwrap().to_seed(""), NetworkKind::Main).unwrap()
+    }
+
+    fn chain_address(chain: &Chain, address_type: AddressType, path: &str) -> String {
+        let secp = Secp256k1::new();
+        let key = root().derive_priv(&secp, &parse_path(path).unwrap()).unwrap().private_key.public_key(&secp);
+
+        chain.address_of(address_type, &key, &chain.main).unwrap()
+    }
+
+    fn address(address_type: AddressType, path: &str) -> String {
+        chain_address(&BITCOIN, address_type, path)
+    }
+
+    #[test]
+    fn bip84_vectors() {
+        assert_eq!(address(AddressType::P2wpkh, "m/84'/0'/0'/0/0"), "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu");
+        assert_eq!(address(AddressType::P2wpkh, "m/84'/0'/0'/1/0"), "bc1q8c6fshw2dlwun7ekn9qwf37cu2rn755upcp6el");
+
+        let (path, zpub) = account_xpub(&root(), XpubFormat::Zpub, &Options::default()).unwrap();
+        assert_eq!(path.to_string(), "84'/0'/0'");
+        assert_eq!(zpub, "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs");
+    }
+
+    #[test]
+    fn bip86_vector() {
+        assert_eq!(address(AddressType::P2tr, "m/86'/0'/0'/0/0"), "bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr");
+    }
+
+    #[test]
+    fn other_chain_vectors() {
+        assert_eq!(chain_address(&DOGECOIN, AddressType::P2pkh, "m/44'/3'/0'/0/0"), "DBus3bamQjgJULBJtYXpEzDWQRwF5iwxgC");
+        assert_eq!(chain_address(&LITECOIN, AddressType::P2wpkh, "m/84'/2'/0'/0/0"), "ltc1qjmxnz78nmc8nq77wuxh25n2es7rzm5c2rkk4wh");
+    }
+
+    /// A CashAddr address, the checksum is the BCH polymod of the prefix and the payload
+    #[test]
+    fn cashaddr_vector() {
+        assert_eq!(chain_address(&BITCOIN_CASH, AddressType::P2pkh, "m/44'/145'/0'/0/0"), "bitcoincash:qqyx49mu0kkn9ftfj6hje6g2wfer34yfnq5tahq3q6");
+    }
+}
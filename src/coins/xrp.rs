@@ -0,0 +1,77 @@
+use std::error::Error;
+use bitcoin::base58;
+use bitcoin::hashes::{hash160, Hash};
+use bitcoin::hex::{Case, DisplayHex};
+use zeroize::Zeroizing;
+use crate::coins::{Coin, Curve, Options};
+
+/// SLIP-44 coin type of the XRP Ledger
+pub const COIN_TYPE: u32 = 144;
+
+const BITCOIN_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const RIPPLE_ALPHABET: &[u8; 58] = b"rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz";
+
+/// Classic `r…` addresses of `m/44'/144'/account'/0/i` as in Xaman, Ledger and Trezor
+pub struct Xrp;
+
+impl Coin for Xrp {
+    fn name(&self) -> &str {
+        "xrp"
+    }
+
+    fn coin_type(&self) -> u32 {
+        COIN_TYPE
+    }
+
+    fn curve(&self, _options: &Options) -> Curve {
+        Curve::Secp256k1
+    }
+
+    fn path_template(&self, _options: &Options) -> String {
+        format!("m/44'/{}'/{{account}}'/0/{{index}}", COIN_TYPE)
+    }
+
+    /// Base58check of the version byte 0 and RIPEMD160(SHA256(compressed public key)) in the Ripple alphabet
+    fn address(&self, public_key: &[u8], _options: &Options) -> Result<String, Box<dyn Error>> {
+        let mut data = vec![0x00];
+        data.extend_from_slice(hash160::Hash::hash(public_key).as_byte_array());
+
+        let address = base58::encode_check(&data).bytes().map(|c| {
+            let position = BITCOIN_ALPHABET.iter().position(|&b| b == c).unwrap();
+            RIPPLE_ALPHABET[position] as char
+        }).collect();
+
+        Ok(address)
+    }
+
+    /// `00` and the hex key, the format of xrpl.js wallets, there is no family seed `s…` for a BIP32 key
+    fn private_key(&self, secret: &[u8], _options: &Options) -> Result<Zeroizing<String>, Box<dyn Error>> {
+        Ok(Zeroizing::new(format!("00{}", Zeroizing::new(secret.to_hex_string(Case::Upper)).as_str())))
+    }
+
+    fn explorer(&self) -> Option<&str> {
+        Some("https://xrpscan.com/account/{address}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bip39::Mnemonic;
+    use crate::coins::{expand_path, Seed};
+
+    /// The alphabet remap gives the `r` of the version byte 0
+    #[test]
+    fn bip44_address() {
+        let mnemonic = Mnemonic::parse("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about").unwrap();
+        let (bytes, entropy) = (mnemonic.to_seed(""), mnemonic.to_entropy());
+        let seed = Seed { bytes: &bytes, entropy: &entropy };
+        let options = Options::default();
+
+        let path = expand_path(&Xrp.path_template(&options), 0, 0);
+        let (_, public) = Xrp.curve(&options).derive(&seed, &path).unwrap();
+
+        assert_eq!(path, "m/44'/144'/0'/0/0");
+        assert_eq!(Xrp.address(&public, &options).unwrap(), "rHsMGQEkVNJmpGWs8XUBoTBiAAbwxZN5v3");
+    }
+}
@@ -0,0 +1,138 @@
+use std::error::Error;
+use std::fs;
+use std::io::{self, Read};
+use crate::book::{add_entry, load_book, print_entry, save_book, BookEntry, BOOK_FILE};
+use crate::coins::registry::Registry;
+use crate::console::{extract_value, take_password};
+use crate::container;
+use crate::crypto::{decrypt_s, Cipher, ECryptoParam};
+use crate::explorers::Explorers;
+use zeroize::Zeroizing;
+
+pub fn run_encrypt(args: &mut Vec<String>) -> Result<(), Box<dyn Error>> {
+    let input = extract_value(args, "--in", "--in")?;
+    let output = extract_value(args, "--out", "--out")?;
+    let cipher = match extract_value(args, "--cipher", "--cipher")? {
+        Some(name) => Cipher::parse(&name).ok_or_else(|| format!("Unknown cipher {}, use aes-256-gcm or xchacha20-poly1305", name))?,
+        None => Cipher::Aes256Gcm,
+    };
+    let password = take_password(args)?;
+
+    let plaintext = read_input(input.as_deref())?;
+    let armored = container::encrypt(&plaintext, &password, cipher)?;
+
+    write_output(output.as_deref(), armored.as_bytes())
+}
+
+pub fn run_decrypt(args: &mut Vec<String>) -> Result<(), Box<dyn Error>> {
+    let input = extract_value(args, "--in", "--in")?;
+    let output = extract_value(args, "--out", "--out")?;
+    let legacy_layers: u32 = parse_opt(extract_value(args, "--legacy_layers", "--legacy_layers")?, 1)?;
+    let legacy_kdf_rounds: u32 = parse_opt(extract_value(args, "--legacy_kdf_rounds", "--legacy_kdf_rounds")?, 0)?;
+    let password = take_password(args)?;
+
+    let text = String::from_utf8(read_input(input.as_deref())?.to_vec())?;
+
+    let plaintext: Zeroizing<Vec<u8>> = if container::is_armored(&text) {
+        container::decrypt(&text, &password)?
+    } else {
+        Zeroizing::new(decrypt_s(text.trim(), ECryptoParam::Password(&password, legacy_kdf_rounds), legacy_layers)?.as_bytes().to_vec())
+    };
+
+    write_output(output.as_deref(), &plaintext)
+}
+
+pub fn run_book(args: &mut Vec<String>) -> Result<(), Box<dyn Error>> {
+    if args.is_empty() {
+        return Err("Book command is required: add, list or search".into());
+    }
+
+    let command = args.remove(0);
+    let file = extract_value(args, "--file", "--file")?.unwrap_or(BOOK_FILE.to_string());
+    let explorers = Explorers::load()?;
+    let registry = Registry::load()?;
+
+    match command.as_str() {
+        "add" => {
+            let wallet = extract_value(args, "-w", "--wallet")?.ok_or("--wallet is required")?;
+            let index = extract_value(args, "-i", "--index")?.ok_or("--index is required")?;
+            let address = extract_value(args, "-a", "--address")?.ok_or("--address is required")?;
+            let label = extract_value(args, "-l", "--label")?.unwrap_or_default();
+            let password = take_password(args)?;
+
+            let entry = BookEntry {
+                wallet,
+                index: index.parse().map_err(|e| format!("Wrong index {:?}: {}", index, e))?,
+                address,
+                label,
+            };
+
+            entry.validate()?;
+
+            let mut entries = load_book(&file, &password)?;
+
+            if !add_entry(&mut entries, entry) {
+                return Err(format!("The address is already in {} for this wallet", file).into());
+            }
+
+            print_entry(entries.last().unwrap(), &explorers, &registry);
+            save_book(&file, &password, &entries)?;
+
+            println!("{} entries in {}", entries.len(), file);
+        }
+        "list" | "search" => {
+            let query = match command.as_str() {
+                "search" => Some(extract_value(args, "-q", "--query")?.ok_or("--query is required")?),
+                _ => None,
+            };
+            let password = take_password(args)?;
+            let entries = load_book(&file, &password)?;
+
+            let found: Vec<&BookEntry> = entries.iter()
+                .filter(|entry| match &query {
+                    Some(query) => entry.matches(query),
+                    None => true,
+                })
+                .collect();
+
+            for entry in found.iter() {
+                print_entry(entry, &explorers, &registry);
+            }
+
+            println!("{} of {} entries in {}", found.len(), entries.len(), file);
+        }
+        _ => return Err(format!("Unknown book command {}", command).into()),
+    }
+
+    Ok(())
+}
+
+fn parse_opt(value: Option<String>, default: u32) -> Result<u32, Box<dyn Error>> {
+    match value {
+        Some(value) => value.parse().map_err(|e| format!("Wrong number {:?}: {}", value, e).into()),
+        None => Ok(default),
+    }
+}
+
+fn read_input(path: Option<&str>) -> Result<Zeroizing<Vec<u8>>, Box<dyn Error>> {
+    match path {
+        Some(path) => fs::read(path)
+            .map(Zeroizing::new)
+            .map_err(|e| format!("Error on read file - {}: {}", path, e).into()),
+        None => {
+            let mut data = Zeroizing::new(vec![]);
+            io::stdin().read_to_end(&mut data)?;
+            Ok(data)
+        }
+    }
+}
+
+fn write_output(path: Option<&str>, data: &[u8]) -> Result<(), Box<dyn Error>> {
+    match path {
+        Some(path) => fs::write(path, data).map_err(|e| format!("Error on write file - {}: {}", path, e).into()),
+        None => {
+            print!("{}", String::from_utf8_lossy(data));
+            Ok(())
+        }
+    }
+}
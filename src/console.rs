@@ -1,4 +1,6 @@
 use std::env;
+use std::error::Error;
+use zeroize::{Zeroize, Zeroizing};
 
 pub fn extract_parameter_and_value(args: &mut Vec<String>, short_param: &str, long_param: &str, use_value: bool) -> Option<(String, Option<String>)> {
     let mut i = 0;
@@ -27,12 +29,127 @@ pub fn extract_parameter_and_value(args: &mut Vec<String>, short_param: &str, lo
     None
 }
 
+pub fn extract_flag(args: &mut Vec<String>, param: &str) -> bool {
+    extract_parameter_and_value(args, param, param, false).is_some()
+}
+
+pub fn extract_value(args: &mut Vec<String>, short_param: &str, long_param: &str) -> Result<Option<String>, Box<dyn Error>> {
+    match extract_parameter_and_value(args, short_param, long_param, true) {
+        Some((_, Some(value))) => Ok(Some(value)),
+        Some((arg, None)) => Err(format!("Wrong argument {}", arg).into()),
+        None => Ok(None),
+    }
+}
+
+/// What is printed for every generated key, see `--show`
+pub struct Show {
+    items: Vec<String>,
+}
+
+impl Show {
+    pub const ITEMS: &'static [&'static str] = &["mnemonic", "seed", "xprv", "fingerprint", "xpub", "ypub", "zpub", "addresses", "privkey", "links"];
+
+    pub fn parse(value: &str) -> Result<Show, Box<dyn Error>> {
+        let mut items: Vec<String> = vec![];
+
+        for item in value.split(',').map(|s| s.trim().to_lowercase()).filter(|s| !s.is_empty()) {
+            if !Show::ITEMS.contains(&item.as_str()) {
+                return Err(format!("Wrong show item {:?}, expected some of {}", item, Show::ITEMS.join(",")).into());
+            }
+
+            items.push(item);
+        }
+
+        if items.is_empty() {
+            return Err("Nothing to show".into());
+        }
+
+        Ok(Show { items })
+    }
+
+    pub fn has(&self, item: &str) -> bool {
+        self.items.iter().any(|s| s == item)
+    }
+
+    /// Anything besides the mnemonic needs the BIP39 seed
+    pub fn needs_seed(&self) -> bool {
+        self.items.iter().any(|s| s != "mnemonic")
+    }
+}
+
+impl Default for Show {
+    fn default() -> Self {
+        Show { items: vec!["mnemonic".to_string()] }
+    }
+}
+
+/// All the words left after the options, joined by spaces
+pub fn take_password(args: &mut Vec<String>) -> Result<Zeroizing<String>, Box<dyn Error>> {
+    let mut words = std::mem::take(args);
+    let password = Zeroizing::new(words.join(" "));
+    words.zeroize();
+
+    if password.is_empty() {
+        return Err("Password is required".into());
+    }
+
+    Ok(password)
+}
+
 pub fn print_help () {
     println!("-h\t--help\t\t--> Print this help");
     println!("-c\t--count\t\t--> Count of keys to generate");
     println!("-f\t--from\t\t--> The index from which the keys will be generated");
     println!("-i\t--key_id\t--> Print key only with that id [from 0]");
+    println!("-n\t--indices\t--> Print keys with ids from the list, e.g. 0-9,42,100-120");
     println!("-w\t--wallet_id\t--> Print wallet only with that id [from 0], see -l, --list");
+    println!("-s\t--show\t\t--> What to print for each key [mnemonic], some of {}", Show::ITEMS.join(","));
+    println!("\t\t\t    xpub, ypub, zpub --> account keys of m/44'/0'/0', m/49'/0'/0', m/84'/0'/0'");
+    println!("\t\t\t    addresses --> receive and change addresses of the wallet's coin");
+    println!("\t\t\t    privkey --> private key of each address, where supported");
+    println!("\t\t\t    links --> block explorer link of each address, the coin's own or an override of explorers.txt");
+    println!("\t--network NAME\t--> main [default], testnet, signet or regtest");
+    println!("-a\t--account N\t--> Account of the derivation paths [0]");
+    println!("-g\t--gap N\t\t--> Count of receive and of change addresses [5]");
+    println!("-t\t--address_types LIST\t--> Address types of btc, ltc, doge and bch, some of bip44,bip49,bip84,bip86");
+    println!("\t\t\t    [bip84 for btc and ltc, bip44 for doge and bch]");
+    println!("\t--eth_path NAME\t--> Ethereum path, standard m/44'/60'/0'/0/i [default], ledger-live m/44'/60'/i'/0/0 or legacy m/44'/60'/0'/i");
+    println!("\t--sol_path NAME\t--> Solana path, standard m/44'/501'/i'/0' [default], ledger m/44'/501'/i', cli m/44'/501' or seed");
+    println!("\t--sui_scheme NAME\t--> Sui keys, ed25519 m/44'/784'/i'/0'/0' [default], secp256k1 m/54'/784'/i'/0/0 or secp256r1 m/74'/784'/i'/0/0");
+    println!("\t--keypair_dir DIR\t--> Write solana-keygen keypair files of Solana addresses into DIR");
+    println!("\t--substrate_path PATH\t--> Junctions of Polkadot and Kusama accounts, e.g. //polkadot or //{{index}} [none]");
+    println!("\t--ss58_prefix N\t--> SS58 prefix of Substrate addresses, 0 Polkadot, 2 Kusama, 42 generic [the chain's own]");
+    println!("\t--encrypt_wallets\t--> Encrypt wallets.txt into wallets.enc with a key from the passphrase");
+    println!("\t--decrypt_wallets\t--> Decrypt wallets.enc back into wallets.txt");
+    println!("\t--save_book PASSWORD\t--> Add the printed addresses to the address book, with --show addresses");
+
+
+    println!();
+    println!("Lines of wallets.txt: Name[:12|16|24[:key=value]...]");
+    println!("\ttype=bip39\t\t--> BIP39 words [default]");
+    println!("\ttype=electrum\t\t--> Electrum segwit seed of 12 words, addresses of m/0'/0/i");
+    println!("\ttype=electrum-standard\t--> Electrum standard seed of 12 words, addresses of m/0/i");
+    println!("\ttype=ton\t\t--> TON mnemonic of 24 words of Tonkeeper and Tonhub, wallet v4R2 and v5R1 addresses");
+    println!("\ttype=monero\t\t--> Monero Polyseed of 16 words, Name:16, and the legacy seed of 25 words of the same keys");
+    println!("\tcoin=NAME\t\t--> Coin of the addresses, btc, ltc, doge, bch, eth, avax, sol, sui, atom, trx, ada, dot, ksm, xlm, xrp, ton, a chain of chains.txt");
+    println!("\t\t\t\t    or a SLIP-44 coin type, e.g. coin=polygon or coin=60 [guessed from the wallet name]");
+    println!("Lines of chains.txt: name = SLIP-44 coin type[, explorer url with {{address}}][, key=value]...");
+    println!("\t\t\t\t--> chains with Ethereum addresses, on m/44'/coin type'/0'/0/i");
+    println!("\thrp=PREFIX\t\t--> Cosmos-SDK chain with bech32 addresses of PREFIX, e.g. hrp=osmo");
+    println!("\tkeys=eth\t\t--> Ethereum-style keys of a Cosmos-SDK chain, e.g. Injective");
+    println!("\tss58=PREFIX\t\t--> Substrate chain with sr25519 keys and SS58 addresses of PREFIX");
+
+    println!();
+    println!("Commands:");
+    println!("--encrypt [--in FILE] [--out FILE] password\t--> Encrypt FILE or stdin into an armored message");
+    println!("\t--cipher NAME\t--> aes-256-gcm [default] or xchacha20-poly1305");
+    println!("--decrypt [--in FILE] [--out FILE] password\t--> Decrypt an armored message");
+    println!("\t--legacy_layers N\t--> Layers count of an old format blob [1]");
+    println!("\t--legacy_kdf_rounds N\t--> Extra password rounds of an old format blob [0]");
+    println!("--book add -w WALLET -i INDEX -a ADDRESS [-l LABEL] password\t--> Save an address into the address book");
+    println!("--book list password\t\t\t--> Print all addresses of the address book");
+    println!("--book search -q TEXT password\t\t--> Print addresses with TEXT in any field");
+    println!("\t--file FILE\t--> Address book file [address_book.asc]");
 
     let exe_path = env::current_exe().unwrap();
     let exe_name = exe_path.file_name().unwrap().to_str().unwrap();
@@ -52,11 +169,44 @@ pub fn print_help () {
 
     println!("Just generate keys with id=2:");
     println!("\t{} -i 2 seed phrase to generate keys", exe_name);
-    println!("\t{} --id 2 seed phrase to generate keys", exe_name);
+    println!("\t{} --key_id 2 seed phrase to generate keys", exe_name);
+    println!();
+
+    println!("Just generate keys with ids 0..9, 42 and 100..120:");
+    println!("\t{} -n 0-9,42,100-120 seed phrase to generate keys", exe_name);
+    println!("\t{} --indices 0-9,42,100-120 seed phrase to generate keys", exe_name);
     println!();
 
     println!("Just generate keys with id=2 in wallet with id=3:");
     println!("\t{} -i 2 -w 3 seed phrase to generate keys", exe_name);
-    println!("\t{} --id 2 --wallet_id 3 seed phrase to generate keys", exe_name);
+    println!("\t{} --key_id 2 --wallet_id 3 seed phrase to generate keys", exe_name);
+    println!();
+
+    println!("Print the master fingerprint and account zpub instead of the words, e.g. for a watch-only wallet:");
+    println!("\t{} -i 0 -w 0 --show fingerprint,zpub seed phrase to generate keys", exe_name);
+    println!();
+
+    println!("Check the first 3 native SegWit and Taproot addresses of the key with id=0 in Electrum:");
+    println!("\t{} -i 0 -w 0 --show addresses -g 3 -t bip84,bip86 seed phrase to generate keys", exe_name);
+    println!();
+
+    println!("Print Polygon addresses with explorer links for a wallet line `MetaMask-Polygon:12:coin=polygon`:");
+    println!("\t{} -i 0 -w 0 --show addresses,links seed phrase to generate keys", exe_name);
+    println!();
+
+    println!("Keep generated keys in an encrypted file:");
+    println!("\t{} -i 2 -w 3 seed phrase to generate keys > keys.txt", exe_name);
+    println!("\t{} --encrypt --in keys.txt --out keys.asc backup password", exe_name);
+    println!("\t{} --decrypt --in keys.asc backup password", exe_name);
+    println!();
+
+    println!("Keep public addresses in an address book protected by its own password:");
+    println!("\t{} --book add -w Electrum -i 0 -a bc1q... -l savings book password", exe_name);
+    println!("\t{} --book search -q savings book password", exe_name);
+    println!("\t{} -i 0 -w 0 --show addresses --save_book book_password seed phrase to generate keys", exe_name);
+    println!();
+
+    println!("Hide the list of wallets, it is decrypted with the same seed phrase on every run:");
+    println!("\t{} --encrypt_wallets seed phrase to generate keys", exe_name);
     println!();
 }
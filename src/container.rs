@@ -0,0 +1,269 @@
+use argon2::{Algorithm, Argon2, Params, Version};
+use base64::{engine::general_purpose, Engine};
+use rand::RngCore;
+use rand::rngs::OsRng;
+use zeroize::Zeroizing;
+use crate::crypto::{make_crypto_params, Cipher, CryptoError, ECryptoParam};
+
+// Layout, all integers big-endian:
+//   magic "B39G" | version u8 | kdf id u8 | kdf params | salt len u8 | salt
+//   | cipher id u8 | nonce len u8 | nonce | AEAD ciphertext with tag
+// Cipher ids are `Cipher::id`: 1 AES-256-GCM with a 12-byte nonce, 2 XChaCha20-Poly1305 with a 24-byte nonce.
+// Everything before the ciphertext is authenticated as associated data.
+
+const MAGIC: &[u8; 4] = b"B39G";
+const VERSION: u8 = 1;
+
+const KDF_ARGON2ID: u8 = 1;
+
+const SALT_LEN: usize = 16;
+
+const ARGON2_M_COST_KIB: u32 = 64 * 1024;
+const ARGON2_T_COST: u32 = 3;
+const ARGON2_P_COST: u32 = 1;
+
+// Upper bounds for parameters read from a message, so a crafted header can't exhaust memory
+const ARGON2_MAX_M_COST_KIB: u32 = 4 * 1024 * 1024;
+const ARGON2_MAX_T_COST: u32 = 64;
+const ARGON2_MAX_P_COST: u32 = 16;
+
+pub const ARMOR_BEGIN: &str = "-----BEGIN BIP39GEN ENCRYPTED MESSAGE-----";
+pub const ARMOR_END: &str = "-----END BIP39GEN ENCRYPTED MESSAGE-----";
+
+pub struct KdfParams {
+    pub m_cost_kib: u32,
+    pub t_cost: u32,
+    pub p_cost: u32,
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        KdfParams { m_cost_kib: ARGON2_M_COST_KIB, t_cost: ARGON2_T_COST, p_cost: ARGON2_P_COST }
+    }
+}
+
+pub fn is_armored(text: &str) -> bool {
+    text.trim_start().starts_with(ARMOR_BEGIN)
+}
+
+pub fn encrypt(plaintext: &[u8], password: &str, cipher: Cipher) -> Result<String, CryptoError> {
+    encrypt_with(plaintext, password, cipher, &KdfParams::default())
+}
+
+fn encrypt_with(plaintext: &[u8], password: &str, cipher: Cipher, kdf: &KdfParams) -> Result<String, CryptoError> {
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+
+    let password_key = derive_key(password, &salt, kdf)?;
+    let (key, nonce) = make_crypto_params(ECryptoParam::KeyRandomNonce(password_key.as_ref()), cipher)?;
+
+    let mut data: Vec<u8> = MAGIC.to_vec();
+    data.push(VERSION);
+    data.push(KDF_ARGON2ID);
+    data.extend(kdf.m_cost_kib.to_be_bytes());
+    data.extend(kdf.t_cost.to_be_bytes());
+    data.extend(kdf.p_cost.to_be_bytes());
+    data.push(SALT_LEN as u8);
+    data.extend(salt);
+    data.push(cipher.id());
+    data.push(nonce.len() as u8);
+    data.extend(nonce.iter());
+
+    let ciphertext = cipher.encrypt(&key, &nonce, plaintext, &data)?;
+
+    data.extend(ciphertext);
+
+    Ok(armor(&data))
+}
+
+pub fn decrypt(armored: &str, password: &str) -> Result<Zeroizing<Vec<u8>>, CryptoError> {
+    let data = dearmor(armored)?;
+    let mut reader = Reader { data: &data, pos: 0 };
+
+    if reader.take(MAGIC.len())? != MAGIC {
+        return Err(CryptoError::Format("not a bip39gen encrypted message".to_string()));
+    }
+
+    let version = reader.u8()?;
+
+    if version != VERSION {
+        return Err(CryptoError::Format(format!("unsupported message version {}", version)));
+    }
+
+    let kdf_id = reader.u8()?;
+
+    if kdf_id != KDF_ARGON2ID {
+        return Err(CryptoError::Format(format!("unsupported KDF id {}", kdf_id)));
+    }
+
+    let kdf = KdfParams { m_cost_kib: reader.u32()?, t_cost: reader.u32()?, p_cost: reader.u32()? };
+
+    if kdf.m_cost_kib > ARGON2_MAX_M_COST_KIB || kdf.t_cost > ARGON2_MAX_T_COST || kdf.p_cost > ARGON2_MAX_P_COST {
+        return Err(CryptoError::Kdf("parameters are out of the supported range".to_string()));
+    }
+
+    let salt_len = reader.u8()? as usize;
+    let salt = reader.take(salt_len)?;
+    let cipher = Cipher::from_id(reader.u8()?)?;
+    let nonce_len = reader.u8()? as usize;
+
+    if nonce_len != cipher.nonce_len() {
+        return Err(CryptoError::BadNonce);
+    }
+
+    let nonce = reader.take(nonce_len)?;
+    let (header, ciphertext) = data.split_at(reader.pos);
+
+    let key = derive_key(password, salt, &kdf)?;
+
+    cipher.decrypt(key.as_ref(), nonce, ciphertext, header).map(Zeroizing::new)
+}
+
+fn derive_key(password: &str, salt: &[u8], kdf: &KdfParams) -> Result<Zeroizing<[u8; 32]>, CryptoError> {
+    let params = Params::new(kdf.m_cost_kib, kdf.t_cost, kdf.p_cost, Some(32))
+        .map_err(|e| CryptoError::Kdf(e.to_string()))?;
+
+    let mut key = Zeroizing::new([0u8; 32]);
+
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(password.as_bytes(), salt, key.as_mut())
+        .map_err(|e| CryptoError::Kdf(e.to_string()))?;
+
+    Ok(key)
+}
+
+fn armor(data: &[u8]) -> String {
+    let encoded = general_purpose::STANDARD.encode(data);
+    let mut lines: Vec<&str> = vec![ARMOR_BEGIN];
+
+    for chunk in encoded.as_bytes().chunks(64) {
+        lines.push(std::str::from_utf8(chunk).unwrap());
+    }
+
+    lines.push(ARMOR_END);
+    lines.join("\n") + "\n"
+}
+
+fn dearmor(armored: &str) -> Result<Vec<u8>, CryptoError> {
+    let body = armored.trim()
+        .strip_prefix(ARMOR_BEGIN)
+        .and_then(|s| s.strip_suffix(ARMOR_END))
+        .ok_or(CryptoError::Format("BEGIN/END armor lines not found".to_string()))?;
+
+    let encoded: String = body.chars().filter(|c| !c.is_whitespace()).collect();
+
+    Ok(general_purpose::STANDARD.decode(encoded)?)
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], CryptoError> {
+        let end = self.pos.checked_add(len)
+            .filter(|&end| end <= self.data.len())
+            .ok_or(CryptoError::Format("message is truncated".to_string()))?;
+
+        let chunk = &self.data[self.pos..end];
+        self.pos = end;
+
+        Ok(chunk)
+    }
+
+    fn u8(&mut self) -> Result<u8, CryptoError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, CryptoError> {
+        let bytes = self.take(4)?;
+
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcoin::hex::DisplayHex;
+    use crate::crypto::decrypt_s;
+
+    const PLAINTEXT: &[u8] = b"legal winner thank year wave sausage worth useful legal winner thank yellow";
+
+    /// Small enough for unoptimized test builds, the parameters are read back from the header
+    const TEST_KDF: KdfParams = KdfParams { m_cost_kib: 64, t_cost: 1, p_cost: 1 };
+
+    fn sealed(cipher: Cipher) -> String {
+        encrypt_with(PLAINTEXT, "correct horse", cipher, &TEST_KDF).unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        for cipher in [Cipher::Aes256Gcm, Cipher::XChaCha20Poly1305] {
+            let armored = sealed(cipher);
+
+            assert!(is_armored(&armored));
+            assert_eq!(decrypt(&armored, "correct horse").unwrap().as_slice(), PLAINTEXT);
+            assert_ne!(armored, sealed(cipher), "salt and nonce are random");
+        }
+    }
+
+    #[test]
+    fn wrong_password() {
+        for cipher in [Cipher::Aes256Gcm, Cipher::XChaCha20Poly1305] {
+            assert!(matches!(decrypt(&sealed(cipher), "correct horse "), Err(CryptoError::AuthFailed)));
+        }
+    }
+
+    /// The header is associated data, a changed salt byte fails like a changed ciphertext byte
+    #[test]
+    fn tampered_message() {
+        for cipher in [Cipher::Aes256Gcm, Cipher::XChaCha20Poly1305] {
+            let data = dearmor(&sealed(cipher)).unwrap();
+            let salt_pos = MAGIC.len() + 2 + 12 + 1;
+
+            for pos in [salt_pos, data.len() - 1] {
+                let mut tampered = data.clone();
+                tampered[pos] ^= 1;
+
+                assert!(matches!(decrypt(&armor(&tampered), "correct horse"), Err(CryptoError::AuthFailed)));
+            }
+
+            let mut tampered = data.clone();
+            tampered[MAGIC.len()] = VERSION + 1;
+            assert!(matches!(decrypt(&armor(&tampered), "correct horse"), Err(CryptoError::Format(_))));
+
+            assert!(matches!(decrypt(&armor(&data[..data.len() / 2]), "correct horse"), Err(CryptoError::AuthFailed | CryptoError::Format(_))));
+        }
+    }
+
+    /// A crafted header can't ask for gigabytes of memory
+    #[test]
+    fn kdf_limits() {
+        let mut data = dearmor(&sealed(Cipher::Aes256Gcm)).unwrap();
+        data[MAGIC.len() + 2..MAGIC.len() + 6].copy_from_slice(&(ARGON2_MAX_M_COST_KIB + 1).to_be_bytes());
+
+        assert!(matches!(decrypt(&armor(&data), "correct horse"), Err(CryptoError::Kdf(_))));
+    }
+
+    /// Argon2id v1.3 test of the reference implementation, m=256 t=2 p=1
+    #[test]
+    fn argon2id_vector() {
+        let key = derive_key("password", b"somesalt", &KdfParams { m_cost_kib: 256, t_cost: 2, p_cost: 1 }).unwrap();
+
+        assert_eq!(key.to_lower_hex_string(), "9dfeb910e80bad0311fee20f9c0e2b12c17987b4cac90c2ef54d5b3021c68bfe");
+    }
+
+    /// A blob of the old format with 3 layers and 5 extra KDF rounds, as `decrypt --legacy_layers 3 --legacy_kdf_rounds 5`
+    /// reads it, made with a separate implementation of `generate_entropy` and AES-256-GCM
+    #[test]
+    fn legacy_blob() {
+        const BLOB: &str = "Enyx/RI4/E2CEyPQCpCLe0NyNkg1KaZDCwp/ArASyGy7PWBsw3fXB/xu8QFzIe0SkyON2XlUkJakwvPBH5sEJfZNJTqJXGA20ha5x62+Kq7jlaLbROiDP7I36yIjCNxyS0qRQSVOY43BlRfjhUyMzTD2pNIfg8XBZssM";
+
+        assert!(!is_armored(BLOB));
+        assert_eq!(decrypt_s(BLOB, ECryptoParam::Password("correct horse", 5), 3).unwrap().as_bytes(), PLAINTEXT);
+        assert!(matches!(decrypt_s(BLOB, ECryptoParam::Password("correct horse", 4), 3), Err(CryptoError::AuthFailed)));
+        assert!(decrypt_s(BLOB, ECryptoParam::Password("correct horse", 5), 2).is_err());
+    }
+}
@@ -0,0 +1,240 @@
+use std::error::Error;
+use bip39::{Language, Mnemonic};
+use bitcoin::bip32::{ChildNumber, DerivationPath, Xpriv, Xpub};
+use bitcoin::hex::DisplayHex;
+use bitcoin::secp256k1::Secp256k1;
+use bitcoin::Address;
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+use zeroize::Zeroizing;
+use crate::coins::{utxo, Options, Output};
+use crate::keys::{root_key, XpubFormat};
+use crate::secure::{join_words, Secret};
+
+/// Electrum seeds always have 12 words, 132 bits
+pub const WORDS: usize = 12;
+
+pub const BITS_PER_WORD: usize = 11;
+
+/// Seed versions of Electrum, the version is the prefix of HMAC-SHA512("Seed version", words)
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SeedVersion {
+    /// P2PKH addresses on `m/0/i`
+    Standard,
+    /// P2WPKH addresses on `m/0'/0/i`
+    Segwit,
+}
+
+impl SeedVersion {
+    pub fn name(self) -> &'static str {
+        match self {
+            SeedVersion::Standard => "standard",
+            SeedVersion::Segwit => "segwit",
+        }
+    }
+
+    fn prefix(self) -> &'static str {
+        match self {
+            SeedVersion::Standard => "01",
+            SeedVersion::Segwit => "100",
+        }
+    }
+
+    fn keystore_path(self) -> DerivationPath {
+        match self {
+            SeedVersion::Standard => DerivationPath::master(),
+            SeedVersion::Segwit => DerivationPath::from(vec![ChildNumber::Hardened { index: 0 }]),
+        }
+    }
+
+    /// `m` or `m/0'`
+    fn keystore_name(self) -> String {
+        let path = self.keystore_path();
+
+        if path.is_master() { "m".to_string() } else { format!("m/{}", path) }
+    }
+
+    fn xpub_format(self) -> XpubFormat {
+        match self {
+            SeedVersion::Standard => XpubFormat::Xpub,
+            SeedVersion::Segwit => XpubFormat::Zpub,
+        }
+    }
+}
+
+/// Electrum's `make_seed` with the random start replaced by the wallet entropy:
+/// the words are base 2048 digits of a number, it is incremented until the words have the version prefix
+/// and are not a valid BIP39 mnemonic, so a wallet can't take them for BIP39 words
+pub fn make_seed(entropy: &[u8], version: SeedVersion) -> Zeroizing<String> {
+    let words = Language::English.word_list();
+    let mut digits: Zeroizing<Vec<usize>> = Zeroizing::new((0..WORDS).map(|i| read_bits(entropy, i * BITS_PER_WORD)).collect());
+
+    // Electrum starts from at least 2^121, so the seed doesn't get shorter than 12 words
+    if digits[WORDS - 1] == 0 {
+        digits[WORDS - 1] = 1;
+    }
+
+    loop {
+        increment(&mut digits, words.len());
+
+        let seed = Zeroizing::new(join_words(digits.iter().map(|&d| words[d])));
+
+        if !is_bip39(&seed) && is_new_seed(&seed, version) {
+            return seed;
+        }
+    }
+}
+
+/// 11 bits from `bit` on, big-endian, bits past the end of the data are zeros
+pub fn read_bits(data: &[u8], bit: usize) -> usize {
+    (bit..bit + BITS_PER_WORD).fold(0, |acc, b| (acc << 1) | (data.get(b / 8).map_or(0, |byte| byte >> (7 - b % 8)) & 1) as usize)
+}
+
+/// Adds one to a little-endian number of base `base`
+pub fn increment(digits: &mut Vec<usize>, base: usize) {
+    for digit in digits.iter_mut() {
+        *digit += 1;
+
+        if *digit < base {
+            return;
+        }
+
+        *digit = 0;
+    }
+
+    digits.push(1);
+}
+
+/// Words with a valid BIP39 checksum, Electrum skips them
+fn is_bip39(seed: &str) -> bool {
+    Mnemonic::parse_in_normalized(Language::English, seed).is_ok()
+}
+
+fn is_new_seed(seed: &str, version: SeedVersion) -> bool {
+    let mut mac = Hmac::<Sha512>::new_from_slice(b"Seed version").unwrap();
+    mac.update(seed.as_bytes());
+
+    mac.finalize().into_bytes()[0..2].to_lower_hex_string().starts_with(version.prefix())
+}
+
+/// Electrum seed to BIP32 seed, PBKDF2-HMAC-SHA512 with the "electrum" salt and an empty passphrase
+pub fn to_seed(seed: &str) -> Secret<Vec<u8>> {
+    let mut bytes = vec![0u8; 64];
+    pbkdf2::pbkdf2_hmac::<Sha512>(seed.as_bytes(), b"electrum", 2048, &mut bytes);
+
+    Secret::new(bytes)
+}
+
+/// Prints the key data selected by `--show` under an Electrum seed
+pub fn print_keys(seed: &str, version: SeedVersion, out: &Output, options: &Options) -> Result<(), Box<dyn Error>> {
+    let show = out.show;
+
+    if !show.needs_seed() {
+        return Ok(());
+    }
+
+    let secp = Secp256k1::new();
+    let bip32_seed = to_seed(seed);
+    let root: Xpriv = root_key(&bip32_seed, options.network_kind())?;
+    let path = version.keystore_path();
+    let keystore = Xpub::from_priv(&secp, &root.derive_priv(&secp, &path)?);
+
+    if show.has("seed") {
+        println!("    seed: {}", Zeroizing::new(bip32_seed.to_lower_hex_string()).as_str());
+    }
+
+    if show.has("xprv") {
+        println!("    xprv: {}", Zeroizing::new(root.to_string()).as_str());
+    }
+
+    if show.has("fingerprint") {
+        println!("    fingerprint: {}", root.fingerprint(&secp));
+    }
+
+    if XpubFormat::ALL.iter().any(|format| show.has(format.name())) {
+        let format = version.xpub_format();
+        println!("    {} {} {}: {}", version.name(), format.name(), version.keystore_name(), format.encode(&keystore, options.network_kind()));
+    }
+
+    if show.has("addresses") {
+        for chain in 0..2 {
+            for index in 0..options.gap {
+                let child = [ChildNumber::from_normal_idx(chain)?, ChildNumber::from_normal_idx(index)?];
+                let key = keystore.derive_pub(&secp, &child)?.to_pub();
+
+                let address = match version {
+                    SeedVersion::Standard => Address::p2pkh(key, options.network),
+                    SeedVersion::Segwit => Address::p2wpkh(&key, options.network),
+                };
+
+                let path = format!("{}/{}/{}", version.keystore_name(), chain, index);
+                out.address(&utxo::BITCOIN, version.name(), &path, &address.to_string());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcoin::{Network, NetworkKind};
+
+    /// Seeds of Electrum's wallet tests
+    const SEGWIT_SEED: &str = "bitter grass shiver impose acquire brush forget axis eager alone wine silver";
+    const STANDARD_SEED: &str = "cycle rocket west magnet parrot shuffle foot correct salt library feed song";
+
+    fn keystore(seed: &str, version: SeedVersion) -> Xpub {
+        let secp = Secp256k1::new();
+        let root = root_key(&to_seed(seed), NetworkKind::Main).unwrap();
+
+        Xpub::from_priv(&secp, &root.derive_priv(&secp, &version.keystore_path()).unwrap())
+    }
+
+    #[test]
+    fn segwit_seed_vector() {
+        let secp = Secp256k1::verification_only();
+        let child = [ChildNumber::from_normal_idx(0).unwrap(), ChildNumber::from_normal_idx(0).unwrap()];
+        let key = keystore(SEGWIT_SEED, SeedVersion::Segwit).derive_pub(&secp, &child).unwrap().to_pub();
+
+        assert!(is_new_seed(SEGWIT_SEED, SeedVersion::Segwit));
+        assert!(!is_bip39(SEGWIT_SEED));
+        assert_eq!(Address::p2wpkh(&key, Network::Bitcoin).to_string(), "bc1q3g5tmkmlvxryhh843v4dz026avatc0zzr6h3af");
+    }
+
+    /// The words after the start have the segwit prefix and a valid BIP39 checksum
+    #[test]
+    fn skips_bip39_mnemonics() {
+        const BIP39_SEGWIT: &str = "ability abandon guard asthma abandon abandon abandon abandon abandon abandon abandon about";
+
+        let list = Language::English.word_list();
+        let mut entropy = [0u8; 17];
+
+        for (n, word) in BIP39_SEGWIT.split(' ').enumerate() {
+            let index = list.iter().position(|w| w == &word).unwrap() - if n == 0 { 1 } else { 0 };
+
+            for bit in 0..BITS_PER_WORD {
+                if (index >> (BITS_PER_WORD - 1 - bit)) & 1 == 1 {
+                    let position = n * BITS_PER_WORD + bit;
+                    entropy[position / 8] |= 0x80 >> (position % 8);
+                }
+            }
+        }
+
+        assert!(is_bip39(BIP39_SEGWIT) && is_new_seed(BIP39_SEGWIT, SeedVersion::Segwit));
+
+        let seed = make_seed(&entropy, SeedVersion::Segwit);
+
+        assert_ne!(seed.as_str(), BIP39_SEGWIT);
+        assert!(!is_bip39(&seed) && is_new_seed(&seed, SeedVersion::Segwit));
+    }
+
+    #[test]
+    fn standard_seed_vector() {
+        let xpub = keystore(STANDARD_SEED, SeedVersion::Standard);
+
+        assert!(is_new_seed(STANDARD_SEED, SeedVersion::Standard));
+        assert_eq!(XpubFormat::Xpub.encode(&xpub, NetworkKind::Main), "xpub661MyMwAqRbcFWohJWt7PHsFEJfZAvw9ZxwQoDa4SoMgsDDM1T7WK3u9E4edkC4ugRnZ8E4xDZRpk8Rnts3Nbt97dPwT52CwBdDWroaZf8U");
+    }
+}
@@ -0,0 +1,86 @@
+use std::error::Error;
+use crate::coins::Coin;
+use crate::files::{lf, wf};
+
+pub const EXPLORERS_FILE: &str = "explorers.txt";
+
+const ADDRESS_PLACEHOLDER: &str = "{address}";
+
+/// Overrides of block explorer URL templates keyed by wallet name or coin, `{address}` is replaced with the address
+pub struct Explorers {
+    templates: Vec<(String, String)>,
+}
+
+impl Explorers {
+    /// Coins have their own explorers, see `Coin::explorer` and chains.txt, the file only overrides them
+    fn defaults() -> Vec<String> {
+        vec![
+            "# key = url template, the key is a wallet name or a coin".to_string(),
+            "# links of the coins and of chains.txt are used unless they are overridden here, e.g.".to_string(),
+            "# btc = https://mempool.space/address/{address}".to_string(),
+        ]
+    }
+
+    /// Reads the overrides, the file is created with the defaults when it is missing
+    pub fn load() -> Result<Explorers, Box<dyn Error>> {
+        let lines: Vec<String> = match lf("./", EXPLORERS_FILE) {
+            Ok(data) => String::from_utf8(data)?.lines().map(String::from).collect(),
+            Err(_) => {
+                let lines = Explorers::defaults();
+                wf("./", EXPLORERS_FILE, &(lines.join("\n") + "\n"))?;
+                lines
+            }
+        };
+
+        Explorers::parse(&lines)
+    }
+
+    fn parse(lines: &[String]) -> Result<Explorers, Box<dyn Error>> {
+        let mut templates: Vec<(String, String)> = vec![];
+
+        for line in lines.iter().map(|line| line.trim()) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, template) = line.split_once('=')
+                .ok_or_else(|| format!("Wrong line in {}: {:?}", EXPLORERS_FILE, line))?;
+
+            let template = template.trim();
+
+            check_template(template, EXPLORERS_FILE, line)?;
+
+            templates.push((key.trim().to_lowercase(), template.to_string()));
+        }
+
+        Ok(Explorers { templates })
+    }
+
+    /// Link for the first key found in the registry, keys are tried in order, e.g. wallet name then coin
+    pub fn link(&self, keys: &[&str], address: &str) -> Option<String> {
+        keys.iter()
+            .find_map(|key| {
+                let key = key.to_lowercase();
+                self.templates.iter().find(|(name, _)| *name == key)
+            })
+            .map(|(_, template)| fill_template(template, address))
+    }
+
+    /// Override of the wallet or of the coin, otherwise the coin's own explorer
+    pub fn coin_link<C: Coin + ?Sized>(&self, wallet: &str, coin: &C, address: &str) -> Option<String> {
+        self.link(&[wallet, coin.name()], address)
+            .or_else(|| coin.explorer().map(|template| fill_template(template, address)))
+    }
+}
+
+pub fn fill_template(template: &str, address: &str) -> String {
+    template.replace(ADDRESS_PLACEHOLDER, address)
+}
+
+pub fn check_template(template: &str, file: &str, line: &str) -> Result<(), Box<dyn Error>> {
+    if !template.contains(ADDRESS_PLACEHOLDER) {
+        return Err(format!("Template in {} has no {}: {:?}", file, ADDRESS_PLACEHOLDER, line).into());
+    }
+
+    Ok(())
+}
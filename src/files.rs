@@ -1,10 +1,19 @@
 use std::error::Error;
 use std::path::PathBuf;
 use std::fs;
-use sha2::{Digest as DigestSha256, Sha256};
 use num_format::Locale;
 use num_format::ToFormattedString;
-use md5::{Md5};
+use bitcoin::hex::DisplayHex;
+use zeroize::Zeroizing;
+use crate::container;
+use crate::crypto::Cipher;
+use crate::electrum::{self, SeedVersion};
+use crate::monero;
+use crate::ton;
+use crate::integrity::{verify_current_exe, Integrity, MANIFEST_FILE};
+
+pub const WALLETS_FILE: &str = "wallets.txt";
+pub const WALLETS_ENC_FILE: &str = "wallets.enc";
 
 pub fn lf(base: &str, filename: &str) -> Result<Vec<u8>, Box<dyn Error>> {
     let path = PathBuf::from(base).join(filename);
@@ -19,82 +28,211 @@ pub fn wf(base: &str, filename: &str, content: &str) -> Result<(), Box<dyn Error
     Ok(())
 }
 
+pub fn rf(base: &str, filename: &str) -> Result<(), Box<dyn Error>> {
+    let path = PathBuf::from(base).join(filename);
+    fs::remove_file(path)?;
+    Ok(())
+}
+
+/// How the words of a wallet are made from its entropy, set with `type=` in the wallet line
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum WalletType {
+    Bip39,
+    Electrum(SeedVersion),
+    Ton,
+    Monero,
+}
+
+/// A line of the wallets file, `Name[:size[:key=value]...]`, the name and the size define the derived words
 #[derive(PartialEq)]
 pub struct WalletInfo {
     pub name: String,
     pub full_name: String,
     pub size: u32,
+    pub options: Vec<(String, String)>,
 }
 
-fn map_wallets(wallets: &Vec<String>) -> Vec<WalletInfo> {
+impl WalletInfo {
+    pub fn option(&self, key: &str) -> Option<&str> {
+        self.options.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str())
+    }
+
+    pub fn wallet_type(&self) -> Result<WalletType, Box<dyn Error>> {
+        let wallet_type = match self.option("type").map(|v| v.to_lowercase()).as_deref() {
+            None | Some("bip39") => WalletType::Bip39,
+            Some("electrum") | Some("electrum-segwit") => WalletType::Electrum(SeedVersion::Segwit),
+            Some("electrum-standard") => WalletType::Electrum(SeedVersion::Standard),
+            Some("ton") => WalletType::Ton,
+            Some("monero") => WalletType::Monero,
+            Some(other) => return Err(format!("Wrong type {:?} of wallet {}", other, self.name).into()),
+        };
+
+        if let WalletType::Electrum(_) = wallet_type {
+            if self.size as usize != electrum::WORDS {
+                return Err(format!("Electrum seeds have {} words, set {}:{} in {}", electrum::WORDS, self.name, electrum::WORDS, WALLETS_FILE).into());
+            }
+        }
+
+        if wallet_type == WalletType::Ton && self.size as usize != ton::WORDS {
+            return Err(format!("TON mnemonics have {} words, set {}:{} in {}", ton::WORDS, self.name, ton::WORDS, WALLETS_FILE).into());
+        }
+
+        if wallet_type == WalletType::Monero && self.size as usize != monero::POLYSEED_WORDS {
+            return Err(format!("Polyseeds have {} words, set {}:{} in {}", monero::POLYSEED_WORDS, self.name, monero::POLYSEED_WORDS, WALLETS_FILE).into());
+        }
+
+        if wallet_type == WalletType::Bip39 && self.size != 12 && self.size != 24 {
+            return Err(format!("BIP39 wallets have 12 or 24 words, set {}:12 or {}:24 in {}", self.name, self.name, WALLETS_FILE).into());
+        }
+
+        Ok(wallet_type)
+    }
+}
+
+/// `size[:key=value]...`, None when the line doesn't follow this format
+fn parse_size_and_options(line: &str) -> Option<(u32, Vec<(String, String)>)> {
+    let mut fields = line.split(':');
+    let size: u32 = fields.next()?.trim().parse().ok()?;
+    let mut options: Vec<(String, String)> = vec![];
+
+    for field in fields {
+        let (key, value) = field.split_once('=')?;
+        options.push((key.trim().to_lowercase(), value.trim().to_string()));
+    }
+
+    Some((size, options))
+}
+
+fn map_wallets(wallets: &[String]) -> Vec<WalletInfo> {
     wallets.iter().map(|s: &String| {
         if let Some((name, num_str_part)) = s.split_once(':') {
-            return match num_str_part.parse::<u32>() {
-                Ok(size) => {
-                    WalletInfo {
-                        name: name.to_string(),
-                        full_name: s.clone(),
-                        size: if size == 12 { 12 } else { 24 },
+            return match parse_size_and_options(num_str_part) {
+                Some((size, options)) => {
+                    let mut info = WalletInfo { name: name.to_string(), full_name: s.clone(), size, options };
+
+                    // Sizes other than 12 were always 24 words, 16 is kept for Polyseeds only
+                    let polyseed = size == monero::POLYSEED_WORDS as u32 && info.wallet_type().ok() == Some(WalletType::Monero);
+
+                    if size != 12 && !polyseed {
+                        info.size = 24;
                     }
+
+                    info
                 }
-                Err(_e) => {
+                None => {
                     WalletInfo {
                         name: name.to_string(),
                         full_name: name.to_string(),
                         size: 12,
+                        options: vec![],
                     }
                 }
             }
         }
 
-        return WalletInfo {
+        WalletInfo {
             name: s.to_string(),
             full_name: s.to_string(),
             size: 12,
-        };
+            options: vec![],
+        }
     }).collect()
 }
 
-pub fn check_files(args: &Vec<String>) -> Result<Vec<WalletInfo>, Box<dyn Error>> {
-    let current_file_data = lf("./", &args[0]);
+fn parse_wallets(wallet_bytes: &[u8]) -> Vec<WalletInfo> {
+    let lines: Vec<String> = wallet_bytes
+        .split(|&b| b == b'\n') // Разбиваем на строки по символу '\n'
+        .map(|line| String::from_utf8(line.to_vec())) // Преобразуем каждую строку в String
+        .filter_map(Result::ok) // Фильтруем успешные преобразования
+        .map(|line| line.trim().to_string())
+        .filter(|line| !line.is_empty())
+        .collect();
+
+    map_wallets(&lines)
+}
 
-    if let Err(_) = current_file_data {
-        return Err(format!("Error on read file - {}", args[0]).into());
+/// Wallets definitions as found on disk, the encrypted variant can be read only after the passphrase is entered
+pub enum WalletsFile {
+    Plain(Vec<WalletInfo>),
+    Encrypted(String),
+}
+
+/// wallets.enc is an armored container, the key derived from the passphrase is its password in hex
+fn wallets_password(key: &[u8]) -> Zeroizing<String> {
+    Zeroizing::new(key.to_lower_hex_string())
+}
+
+fn decrypt_wallets_bytes(encoded: &str, key: &[u8]) -> Result<Zeroizing<Vec<u8>>, Box<dyn Error>> {
+    container::decrypt(encoded, &wallets_password(key))
+        .map_err(|e| format!("Can't decrypt {}, wrong passphrase? {}", WALLETS_ENC_FILE, e).into())
+}
+
+pub fn decrypt_wallets(encoded: &str, key: &[u8]) -> Result<Vec<WalletInfo>, Box<dyn Error>> {
+    Ok(parse_wallets(&decrypt_wallets_bytes(encoded, key)?))
+}
+
+pub fn encrypt_wallets_file(key: &[u8]) -> Result<(), Box<dyn Error>> {
+    let wallet_bytes = lf("./", WALLETS_FILE)
+        .map_err(|_| format!("Error on read file - {}", WALLETS_FILE))?;
+
+    wf("./", WALLETS_ENC_FILE, &container::encrypt(&wallet_bytes, &wallets_password(key), Cipher::Aes256Gcm)?)?;
+
+    if *decrypt_wallets_bytes(&lf_string(WALLETS_ENC_FILE)?, key)? != wallet_bytes {
+        return Err(format!("Verification of {} failed", WALLETS_ENC_FILE).into());
     }
 
-    if let Ok(file_data) = current_file_data {
-        let mut hasher_sha256 = Sha256::new();
-        hasher_sha256.update(&file_data);
-        let res: String = hasher_sha256.finalize_reset().to_vec().iter().map(|byte| format!("{:02x}", byte)).collect();
-        println!("sha256: {:?}", res);
+    rf("./", WALLETS_FILE)
+}
 
-        let mut hasher_md5 = Md5::new();
-        hasher_md5.update(&file_data);
-        let result = hasher_md5.finalize();
-        let res: String = result.iter().map(|byte| format!("{:02x}", byte)).collect();
-        println!("md5: {:?}", res);
+pub fn decrypt_wallets_file(key: &[u8]) -> Result<(), Box<dyn Error>> {
+    let wallet_bytes = decrypt_wallets_bytes(&lf_string(WALLETS_ENC_FILE)?, key)?;
 
-        println!("size: {}", file_data.len().to_formatted_string(&Locale::en));
+    wf("./", WALLETS_FILE, std::str::from_utf8(&wallet_bytes)?)?;
 
-        println!();
+    rf("./", WALLETS_ENC_FILE)
+}
 
-        // println!("source: https://github.com/PHPCraftdream/bip39gen");
-        // println!("release: https://github.com/PHPCraftdream/bip39gen/releases/tag/0.0.1");
-        println!("download: https://t.me/bip39gen");
-        println!();
+fn lf_string(filename: &str) -> Result<String, Box<dyn Error>> {
+    let data = lf("./", filename)
+        .map_err(|_| format!("Error on read file - {}", filename))?;
 
-        println!("learn training: https://t.me/crypto_pokoi");
-        println!();
+    Ok(String::from_utf8(data)?)
+}
 
-        println!("learn YouTube: https://www.youtube.com/watch?v=MviHhLFl8Tg&list=PLwMf5sCHpgUas6rN3wngs-bmOhcMGyeVu");
-        println!("learn RuTube: https://rutube.ru/plst/1076664/");
+pub fn check_files() -> Result<WalletsFile, Box<dyn Error>> {
+    let report = verify_current_exe()?;
 
-        println!();
+    match &report.status {
+        Integrity::Verified { name } => {
+            println!("integrity: VERIFIED - {} from the signed {}", name, MANIFEST_FILE);
+        }
+        Integrity::Unverified(reason) => {
+            println!("integrity: UNVERIFIED - {}", reason);
+            println!("sha256: {}", report.sha256);
+        }
     }
 
+    println!("file: {}", report.path.display());
+    println!("size: {}", report.size.to_formatted_string(&Locale::en));
+
+    println!();
+
+    // println!("source: https://github.com/PHPCraftdream/bip39gen");
+    // println!("release: https://github.com/PHPCraftdream/bip39gen/releases/tag/0.0.1");
+    println!("download: https://t.me/bip39gen");
+    println!();
+
+    println!("learn training: https://t.me/crypto_pokoi");
+    println!();
+
+    println!("learn YouTube: https://www.youtube.com/watch?v=MviHhLFl8Tg&list=PLwMf5sCHpgUas6rN3wngs-bmOhcMGyeVu");
+    println!("learn RuTube: https://rutube.ru/plst/1076664/");
+
+    println!();
+
     let cm_file = lf("./", "cm.bat");
 
-    if let Err(_) = cm_file {
+    if cm_file.is_err() {
         wf("./", "cm.bat", "cmd")?;
     }
 
@@ -106,9 +244,13 @@ pub fn check_files(args: &Vec<String>) -> Result<Vec<WalletInfo>, Box<dyn Error>
         }
     }
 
-    let w_file = lf("./", "wallets.txt");
+    if let Ok(enc_bytes) = lf("./", WALLETS_ENC_FILE) {
+        return Ok(WalletsFile::Encrypted(String::from_utf8(enc_bytes)?));
+    }
+
+    let w_file = lf("./", WALLETS_FILE);
 
-    if let Err(_) = w_file {
+    if w_file.is_err() {
         let wallets: Vec<String> = vec![
             "Electrum:24".to_string(),
             "Ethereum-MyCrypto:24".to_string(),
@@ -118,22 +260,48 @@ pub fn check_files(args: &Vec<String>) -> Result<Vec<WalletInfo>, Box<dyn Error>
             "Doge-Exodus".to_string(),
         ];
 
-        wf("./", "wallets.txt", &wallets.join("\n"))?;
+        wf("./", WALLETS_FILE, &wallets.join("\n"))?;
 
-        return Ok(map_wallets(&wallets));
+        return Ok(WalletsFile::Plain(map_wallets(&wallets)));
     }
 
     if let Ok(wallet_bytes) = w_file {
-        let lines: Vec<String> = wallet_bytes
-            .split(|&b| b == b'\n') // Разбиваем на строки по символу '\n'
-            .map(|line| String::from_utf8(line.to_vec())) // Преобразуем каждую строку в String
-            .filter_map(Result::ok) // Фильтруем успешные преобразования
-            .map(|line| line.trim().to_string())
-            .filter(|line| line.len() > 0)
-            .collect();
-
-        return Ok(map_wallets(&lines));
+        return Ok(WalletsFile::Plain(parse_wallets(&wallet_bytes)));
+    }
+
+    Err("Unknown error".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wallet(line: &str) -> WalletInfo {
+        map_wallets(&[line.to_string()]).remove(0)
     }
 
-    return Err("Unknown error".into());
+    /// Lines written before Polyseeds keep their 24 words
+    #[test]
+    fn bip39_sizes() {
+        for line in ["Wallet:16", "Wallet:18", "Wallet:24:coin=eth", "Wallet:16:type=bip39"] {
+            let info = wallet(line);
+
+            assert_eq!(info.size, 24, "{}", line);
+            assert!(info.wallet_type().is_ok());
+        }
+
+        assert_eq!(wallet("Wallet:12").size, 12);
+        assert_eq!(wallet("Wallet").size, 12);
+    }
+
+    #[test]
+    fn polyseed_sizes() {
+        let info = wallet("Feather:16:type=monero");
+
+        assert_eq!(info.size, 16);
+        assert_eq!(info.wallet_type().unwrap(), WalletType::Monero);
+
+        assert!(wallet("Feather:24:type=monero").wallet_type().is_err());
+        assert!(wallet("Feather:12:type=monero").wallet_type().is_err());
+    }
 }
@@ -0,0 +1,120 @@
+use std::error::Error;
+
+/// Set of key indices to generate, kept as sorted, non-overlapping inclusive ranges.
+pub struct IndexSelection {
+    ranges: Vec<(u32, u32)>,
+}
+
+impl IndexSelection {
+    pub fn single(index: u32) -> IndexSelection {
+        IndexSelection { ranges: vec![(index, index)] }
+    }
+
+    pub fn window(from: u32, count: u32) -> Result<IndexSelection, Box<dyn Error>> {
+        if count == 0 {
+            return Ok(IndexSelection { ranges: vec![] });
+        }
+
+        let to = from.checked_add(count - 1)
+            .ok_or_else(|| format!("Index overflow: from {} + count {} exceeds {}", from, count, u32::MAX))?;
+
+        Ok(IndexSelection { ranges: vec![(from, to)] })
+    }
+
+    /// Parses a list like `0-9,42,100-120`.
+    pub fn parse(spec: &str) -> Result<IndexSelection, Box<dyn Error>> {
+        let mut ranges: Vec<(u32, u32)> = vec![];
+
+        for part in spec.split(',').map(|s| s.trim()).filter(|s| !s.is_empty()) {
+            let (start, end) = match part.split_once('-') {
+                Some((start, end)) => (parse_index(start)?, parse_index(end)?),
+                None => {
+                    let index = parse_index(part)?;
+                    (index, index)
+                }
+            };
+
+            if start > end {
+                return Err(format!("Wrong index range {}", part).into());
+            }
+
+            ranges.push((start, end));
+        }
+
+        if ranges.is_empty() {
+            return Err(format!("Wrong index list {:?}", spec).into());
+        }
+
+        ranges.sort();
+
+        let mut merged: Vec<(u32, u32)> = vec![];
+
+        for (start, end) in ranges {
+            if let Some(last) = merged.last_mut() {
+                if start <= last.1.saturating_add(1) {
+                    last.1 = last.1.max(end);
+                    continue;
+                }
+            }
+
+            merged.push((start, end));
+        }
+
+        Ok(IndexSelection { ranges: merged })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = u32> + '_ {
+        self.ranges.iter().flat_map(|&(start, end)| start..=end)
+    }
+}
+
+fn parse_index(value: &str) -> Result<u32, Box<dyn Error>> {
+    let value = value.trim();
+
+    value.parse::<u32>()
+        .map_err(|e| format!("Wrong index {:?}: {}", value, e).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn indices(spec: &str) -> Vec<u32> {
+        IndexSelection::parse(spec).unwrap().iter().collect()
+    }
+
+    #[test]
+    fn parses_lists_and_ranges() {
+        assert_eq!(indices("0-2,42, 5-6"), vec![0, 1, 2, 5, 6, 42]);
+    }
+
+    #[test]
+    fn merges_duplicates_and_overlaps() {
+        assert_eq!(indices("3,1-3,2,3,0-1"), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn rejects_reversed_ranges() {
+        assert!(IndexSelection::parse("5-3").is_err());
+    }
+
+    #[test]
+    fn rejects_overflow() {
+        assert!(IndexSelection::parse("4294967296").is_err());
+        assert!(IndexSelection::parse("0-4294967296").is_err());
+        assert!(IndexSelection::parse("-1").is_err());
+        assert!(IndexSelection::window(u32::MAX, 2).is_err());
+    }
+
+    #[test]
+    fn reaches_u32_max() {
+        assert_eq!(indices("4294967294-4294967295,4294967295"), vec![u32::MAX - 1, u32::MAX]);
+        assert_eq!(IndexSelection::window(u32::MAX, 1).unwrap().iter().collect::<Vec<u32>>(), vec![u32::MAX]);
+    }
+
+    #[test]
+    fn rejects_empty_lists() {
+        assert!(IndexSelection::parse("").is_err());
+        assert!(IndexSelection::parse(" , ").is_err());
+    }
+}
@@ -0,0 +1,164 @@
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use base64::{engine::general_purpose, Engine};
+use ed25519_dalek::{Signature, VerifyingKey};
+use sha2::{Digest, Sha256};
+
+pub const MANIFEST_FILE: &str = "SHA256SUMS";
+pub const MANIFEST_SIG_FILE: &str = "SHA256SUMS.sig";
+
+/// Base64 of the Ed25519 public key of the release signer, set at build time,
+/// the raw 32 bytes or the SubjectPublicKeyInfo DER that `openssl pkey -pubout -outform DER` writes
+const RELEASE_PUBLIC_KEY: Option<&str> = option_env!("BIP39GEN_RELEASE_PUBLIC_KEY");
+
+/// DER of SubjectPublicKeyInfo with the Ed25519 OID, followed by the 32 key bytes
+const ED25519_SPKI_PREFIX: [u8; 12] = [0x30, 0x2a, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x03, 0x21, 0x00];
+
+pub enum Integrity {
+    Verified { name: String },
+    Unverified(String),
+}
+
+pub struct IntegrityReport {
+    pub path: PathBuf,
+    pub sha256: String,
+    pub size: usize,
+    pub status: Integrity,
+}
+
+pub fn verify_current_exe() -> Result<IntegrityReport, Box<dyn Error>> {
+    let path = env::current_exe()
+        .and_then(fs::canonicalize)
+        .map_err(|e| format!("Can't locate the executable: {}", e))?;
+
+    let data = fs::read(&path)
+        .map_err(|e| format!("Error on read file - {}: {}", path.display(), e))?;
+
+    let sha256 = hex(&Sha256::digest(&data));
+    let dir = path.parent().unwrap_or(Path::new("."));
+    let status = check_manifest(RELEASE_PUBLIC_KEY, dir, &sha256).unwrap_or_else(Integrity::Unverified);
+
+    Ok(IntegrityReport { path, sha256, size: data.len(), status })
+}
+
+fn check_manifest(public_key: Option<&str>, dir: &Path, sha256: &str) -> Result<Integrity, String> {
+    let public_key = public_key
+        .ok_or("this build has no release public key")?;
+
+    let key_bytes: [u8; 32] = general_purpose::STANDARD.decode(public_key.trim())
+        .ok()
+        .map(|bytes| match bytes.strip_prefix(&ED25519_SPKI_PREFIX) {
+            Some(key) => key.to_vec(),
+            None => bytes,
+        })
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or("the release public key is malformed")?;
+
+    let verifying_key = VerifyingKey::from_bytes(&key_bytes)
+        .map_err(|_| "the release public key is malformed")?;
+
+    let manifest = fs::read(dir.join(MANIFEST_FILE))
+        .map_err(|_| format!("{} not found next to the executable", MANIFEST_FILE))?;
+
+    let sig_text = fs::read_to_string(dir.join(MANIFEST_SIG_FILE))
+        .map_err(|_| format!("{} not found next to the executable", MANIFEST_SIG_FILE))?;
+
+    let sig_bytes: [u8; 64] = general_purpose::STANDARD.decode(sig_text.trim())
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or(format!("{} is malformed", MANIFEST_SIG_FILE))?;
+
+    verifying_key.verify_strict(&manifest, &Signature::from_bytes(&sig_bytes))
+        .map_err(|_| format!("{} signature is invalid", MANIFEST_FILE))?;
+
+    let manifest = String::from_utf8(manifest)
+        .map_err(|_| format!("{} is not utf-8", MANIFEST_FILE))?;
+
+    // sha256sum format: "<hex>  <name>", the name may have a leading '*' in binary mode
+    for line in manifest.lines() {
+        if let Some((hash, name)) = line.trim().split_once(char::is_whitespace) {
+            if hash.eq_ignore_ascii_case(sha256) {
+                let name = name.trim().trim_start_matches('*').to_string();
+                return Ok(Integrity::Verified { name });
+            }
+        }
+    }
+
+    Err(format!("sha256 of the executable is not listed in {}", MANIFEST_FILE))
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use ed25519_dalek::{Signer, SigningKey};
+
+    /// Tests run in parallel, each check gets its own directory
+    static CHECKS: AtomicUsize = AtomicUsize::new(0);
+
+    const SHA256: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+    const MANIFEST: &str = "0000000000000000000000000000000000000000000000000000000000000000  bip39gen.exe\n";
+
+    /// Public key and signature of MANIFEST by the key of 32 bytes 0x07, made with OpenSSL
+    const SPKI_KEY: &str = "MCowBQYDK2VwAyEA6kpsY+KcUgq+9VB7Ey7F+ZVHdq6+vnuSQh7qaRRG0iw=";
+    const SIGNATURE: &str = "Y/ldRIl3wosOTg94fL9ArGAh2n+VlxqKpbmGyVIFn7UgGU8bk78LwoWsNUc2ULG2eDToNlW7A+njgG5Rv9CGAA==";
+
+    fn check(public_key: &str, manifest: &str, signature: &str, sha256: &str) -> Result<Integrity, String> {
+        let dir = env::temp_dir().join(format!("bip39gen-integrity-{}-{}", std::process::id(), CHECKS.fetch_add(1, Ordering::Relaxed)));
+
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join(MANIFEST_FILE), manifest).unwrap();
+        fs::write(dir.join(MANIFEST_SIG_FILE), signature).unwrap();
+
+        let result = check_manifest(Some(public_key), &dir, sha256);
+        fs::remove_dir_all(&dir).unwrap();
+
+        result
+    }
+
+    fn raw_key() -> String {
+        general_purpose::STANDARD.encode(SigningKey::from_bytes(&[7u8; 32]).verifying_key().as_bytes())
+    }
+
+    #[test]
+    fn good_signature() {
+        for public_key in [raw_key().as_str(), SPKI_KEY] {
+            match check(public_key, MANIFEST, SIGNATURE, SHA256) {
+                Ok(Integrity::Verified { name }) => assert_eq!(name, "bip39gen.exe"),
+                _ => panic!("the manifest is not verified"),
+            }
+        }
+
+        let signature = general_purpose::STANDARD.encode(SigningKey::from_bytes(&[7u8; 32]).sign(MANIFEST.as_bytes()).to_bytes());
+        assert_eq!(signature, SIGNATURE);
+    }
+
+    #[test]
+    fn bad_signature() {
+        let other = general_purpose::STANDARD.encode(SigningKey::from_bytes(&[8u8; 32]).sign(MANIFEST.as_bytes()).to_bytes());
+        let changed = MANIFEST.replace("bip39gen", "bip39gem");
+
+        assert!(check(SPKI_KEY, MANIFEST, &other, SHA256).is_err());
+        assert!(check(SPKI_KEY, &changed, SIGNATURE, SHA256).is_err());
+        assert!(check(SPKI_KEY, MANIFEST, "not base64", SHA256).is_err());
+    }
+
+    #[test]
+    fn other_hash() {
+        let sha256 = format!("{}1", &SHA256[1..]);
+
+        assert_eq!(check(SPKI_KEY, MANIFEST, SIGNATURE, &sha256).err().unwrap(), format!("sha256 of the executable is not listed in {}", MANIFEST_FILE));
+    }
+
+    #[test]
+    fn wrong_keys() {
+        assert!(check(&SPKI_KEY[4..], MANIFEST, SIGNATURE, SHA256).is_err());
+        assert!(check_manifest(None, Path::new("."), SHA256).is_err());
+    }
+}
@@ -0,0 +1,132 @@
+use std::error::Error;
+use bip39::Mnemonic;
+use bitcoin::base58;
+use bitcoin::bip32::{ChildNumber, DerivationPath, Xpriv, Xpub};
+use bitcoin::hex::DisplayHex;
+use bitcoin::secp256k1::Secp256k1;
+use bitcoin::NetworkKind;
+use zeroize::Zeroizing;
+use crate::coins::{utxo, Coin, Options, Output, Seed};
+use crate::files::WalletInfo;
+use crate::secure::Secret;
+
+/// Account extended public key encodings, the version bytes tell wallets which script type to use
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum XpubFormat {
+    /// BIP44 legacy P2PKH
+    Xpub,
+    /// BIP49 nested SegWit P2SH-P2WPKH
+    Ypub,
+    /// BIP84 native SegWit P2WPKH
+    Zpub,
+}
+
+impl XpubFormat {
+    pub const ALL: [XpubFormat; 3] = [XpubFormat::Xpub, XpubFormat::Ypub, XpubFormat::Zpub];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            XpubFormat::Xpub => "xpub",
+            XpubFormat::Ypub => "ypub",
+            XpubFormat::Zpub => "zpub",
+        }
+    }
+
+    pub fn purpose(self) -> u32 {
+        match self {
+            XpubFormat::Xpub => 44,
+            XpubFormat::Ypub => 49,
+            XpubFormat::Zpub => 84,
+        }
+    }
+
+    /// Xpub with the version bytes of the format
+    pub fn encode(self, xpub: &Xpub, network: NetworkKind) -> String {
+        let mut data = xpub.encode();
+        data[0..4].copy_from_slice(&self.version(network));
+
+        base58::encode_check(&data)
+    }
+
+    /// Test networks use tpub, upub and vpub
+    fn version(self, network: NetworkKind) -> [u8; 4] {
+        match (self, network) {
+            (XpubFormat::Xpub, NetworkKind::Main) => [0x04, 0x88, 0xb2, 0x1e],
+            (XpubFormat::Ypub, NetworkKind::Main) => [0x04, 0x9d, 0x7c, 0xb2],
+            (XpubFormat::Zpub, NetworkKind::Main) => [0x04, 0xb2, 0x47, 0x46],
+            (XpubFormat::Xpub, NetworkKind::Test) => [0x04, 0x35, 0x87, 0xcf],
+            (XpubFormat::Ypub, NetworkKind::Test) => [0x04, 0x4a, 0x52, 0x62],
+            (XpubFormat::Zpub, NetworkKind::Test) => [0x04, 0x5f, 0x1c, 0xf6],
+        }
+    }
+}
+
+/// BIP39 seed of a mnemonic with an empty passphrase
+pub fn seed(mnemonic: &Mnemonic) -> Secret<Vec<u8>> {
+    Secret::new(mnemonic.to_seed("").to_vec())
+}
+
+pub fn root_key(seed: &[u8], network: NetworkKind) -> Result<Xpriv, Box<dyn Error>> {
+    Ok(Xpriv::new_master(network, seed)?)
+}
+
+/// Account path `m/purpose'/coin_type'/account'`
+pub fn account_path(purpose: u32, coin_type: u32, account: u32) -> Result<DerivationPath, Box<dyn Error>> {
+    Ok(DerivationPath::from(vec![
+        ChildNumber::from_hardened_idx(purpose)?,
+        ChildNumber::from_hardened_idx(coin_type)?,
+        ChildNumber::from_hardened_idx(account)?,
+    ]))
+}
+
+pub fn account_xpub(root: &Xpriv, format: XpubFormat, options: &Options) -> Result<(DerivationPath, String), Box<dyn Error>> {
+    let secp = Secp256k1::new();
+    let path = account_path(format.purpose(), options.coin_type(utxo::BITCOIN.coin_type), options.account)?;
+    let xpub = Xpub::from_priv(&secp, &root.derive_priv(&secp, &path)?);
+
+    Ok((path, format.encode(&xpub, options.network_kind())))
+}
+
+/// Prints the key data selected by `--show` under a generated mnemonic
+pub fn print_keys(item: &WalletInfo, mnemonic: &Mnemonic, out: &Output, coin: Option<&dyn Coin>, options: &Options) -> Result<(), Box<dyn Error>> {
+    let show = out.show;
+
+    if !show.needs_seed() {
+        return Ok(());
+    }
+
+    let secp = Secp256k1::new();
+    let seed = seed(mnemonic);
+    let root = root_key(&seed, options.network_kind())?;
+
+    if show.has("seed") {
+        println!("    seed: {}", Zeroizing::new(seed.to_lower_hex_string()).as_str());
+    }
+
+    if show.has("xprv") {
+        println!("    xprv: {}", Zeroizing::new(root.to_string()).as_str());
+    }
+
+    if show.has("fingerprint") {
+        println!("    fingerprint: {}", root.fingerprint(&secp));
+    }
+
+    for format in XpubFormat::ALL {
+        if show.has(format.name()) {
+            let (path, xpub) = account_xpub(&root, format, options)?;
+            println!("    {} m/{}: {}", format.name(), path, xpub);
+        }
+    }
+
+    if show.has("addresses") {
+        let entropy = Zeroizing::new(mnemonic.to_entropy());
+        let seed = Seed { bytes: &seed, entropy: &entropy };
+
+        match coin {
+            Some(coin) => coin.print_addresses(&seed, out, options)?,
+            None => println!("    addresses: no coin in the name of {}, set coin= in wallets.txt", item.name),
+        }
+    }
+
+    Ok(())
+}
@@ -1,39 +1,80 @@
 extern crate core;
 
+mod bip32_ed25519;
+mod book;
+mod coins;
 mod files;
 mod console;
+mod commands;
+mod container;
 mod crypto;
-
-use std::{env};
+mod electrum;
+mod explorers;
+mod integrity;
+mod indices;
+mod keys;
+mod monero;
+mod secure;
+mod slip10;
+mod ton;
+
+use std::cell::RefCell;
+use std::{env, process};
 use std::error::Error;
 use bip39::Mnemonic;
-use crate::console::{extract_parameter_and_value, print_help};
-use crate::crypto::{check_first_arg, generate_entropy};
-use crate::files::{check_files, WalletInfo};
-
-fn main() -> Result<(), Box<dyn Error>> {
-    let mut args: Vec<String> = env::args().map(|s| s.trim().to_string()).collect();
+use crate::book::{load_book, save_book, BOOK_FILE};
+use crate::coins::registry::Registry;
+use crate::coins::{Options, Output};
+use crate::commands::{run_book, run_decrypt, run_encrypt};
+use crate::console::{extract_flag, extract_parameter_and_value, extract_value, print_help, Show};
+use crate::crypto::generate_entropy;
+use crate::explorers::Explorers;
+use crate::files::{check_files, decrypt_wallets, decrypt_wallets_file, encrypt_wallets_file, WalletInfo, WalletType, WalletsFile, WALLETS_ENC_FILE, WALLETS_FILE};
+use crate::indices::IndexSelection;
+use crate::keys::print_keys;
+use crate::secure::{harden_process, join_words, Secret};
+use zeroize::{Zeroize, Zeroizing};
+
+fn main() {
+    if let Err(e) = run() {
+        eprintln!();
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    }
+}
 
-    let wallets: Vec<WalletInfo> = check_files(&args)?;
+fn run() -> Result<(), Box<dyn Error>> {
+    harden_process();
 
-    println!("List of wallets: ");
-    println!();
+    let mut args: Vec<String> = env::args().map(|s| s.trim().to_string()).collect();
 
-    for (ind, init) in wallets.iter().enumerate() {
-        println!("{} - {} - {}", ind, init.name, init.size);
+    match args.get(1).map(|s| s.as_str()) {
+        Some("--encrypt") => return run_encrypt(&mut args.split_off(2)),
+        Some("--decrypt") => return run_decrypt(&mut args.split_off(2)),
+        Some("--book") => return run_book(&mut args.split_off(2)),
+        _ => {}
     }
 
-    println!();
+    let wallets_file: WalletsFile = check_files()?;
 
-
-    if check_first_arg(&args) {
-        return Ok(());
+    match &wallets_file {
+        WalletsFile::Plain(wallets) => print_wallets(wallets),
+        WalletsFile::Encrypted(_) => {
+            println!("List of wallets is encrypted in {}, it will be shown after the passphrase", WALLETS_ENC_FILE);
+            println!();
+        }
     }
 
-    let mut count = 10_u16;
-    let mut from = 0_u16;
-    let mut id: Option<usize> = None;
+
+    let mut count: Option<u32> = None;
+    let mut from: Option<u32> = None;
+    let mut id: Option<u32> = None;
+    let mut list: Option<IndexSelection> = None;
     let mut wid: Option<usize> = None;
+    let mut show = Show::default();
+    let mut encrypt_wallets = false;
+    let mut decrypt_wallets_back = false;
+    let mut book_password: Option<Zeroizing<String>> = None;
 
     if args.contains(&"--help".to_string()) || args.contains(&"-h".to_string()) {
         print_help();
@@ -42,8 +83,9 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     if let Some((arg, value)) = extract_parameter_and_value(&mut args, "-c", "--count", true) {
         if let Some(value) = value {
-            count = value.parse()?;
-            println!("count = {}", count);
+            let value: u32 = value.parse().map_err(|e| format!("Wrong count {:?}: {}", value, e))?;
+            count = Some(value);
+            println!("count = {}", value);
         } else {
             return Err(format!("Wrong argument {}", arg).into());
         }
@@ -51,8 +93,9 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     if let Some((arg, value)) = extract_parameter_and_value(&mut args, "-f", "--from", true) {
         if let Some(value) = value {
-            from = value.parse()?;
-            println!("from = {}", from);
+            let value: u32 = value.parse().map_err(|e| format!("Wrong from {:?}: {}", value, e))?;
+            from = Some(value);
+            println!("from = {}", value);
         } else {
             return Err(format!("Wrong argument {}", arg).into());
         }
@@ -60,7 +103,7 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     if let Some((arg, value)) = extract_parameter_and_value(&mut args, "-i", "--key_id", true) {
         if let Some(value) = value {
-            let key_id = value.parse()?;
+            let key_id: u32 = value.parse().map_err(|e| format!("Wrong key_id {:?}: {}", value, e))?;
             id = Some(key_id);
             println!("key_id = {}", key_id);
         } else {
@@ -68,13 +111,22 @@ fn main() -> Result<(), Box<dyn Error>> {
         }
     }
 
+    if let Some((arg, value)) = extract_parameter_and_value(&mut args, "-n", "--indices", true) {
+        if let Some(value) = value {
+            list = Some(IndexSelection::parse(&value)?);
+            println!("indices = {}", value);
+        } else {
+            return Err(format!("Wrong argument {}", arg).into());
+        }
+    }
+
     if let Some((arg, value)) = extract_parameter_and_value(&mut args, "-w", "--wallet_id", true) {
         if let Some(value) = value {
             let wallet_id = value.parse()?;
             wid = Some(wallet_id);
 
-            if wallets.get(wallet_id) == None {
-                return Err(format!("Wrong wallet id {}", wallet_id).into());
+            if let WalletsFile::Plain(wallets) = &wallets_file {
+                check_wallet_id(wallets, wallet_id)?;
             }
 
             println!("wallet_id = {}", wallet_id);
@@ -83,6 +135,60 @@ fn main() -> Result<(), Box<dyn Error>> {
         }
     }
 
+    if let Some((arg, value)) = extract_parameter_and_value(&mut args, "-s", "--show", true) {
+        if let Some(value) = value {
+            show = Show::parse(&value)?;
+            println!("show = {}", value);
+        } else {
+            return Err(format!("Wrong argument {}", arg).into());
+        }
+    }
+
+    let options = Options::extract(&mut args)?;
+
+    if extract_flag(&mut args, "--encrypt_wallets") {
+        if let WalletsFile::Encrypted(_) = &wallets_file {
+            return Err(format!("{} is already encrypted", WALLETS_ENC_FILE).into());
+        }
+
+        encrypt_wallets = true;
+        println!("encrypt_wallets = true");
+    }
+
+    if extract_flag(&mut args, "--decrypt_wallets") {
+        if let WalletsFile::Plain(_) = &wallets_file {
+            return Err(format!("{} is not encrypted", WALLETS_FILE).into());
+        }
+
+        decrypt_wallets_back = true;
+        println!("decrypt_wallets = true");
+    }
+
+    if let Some(password) = extract_value(&mut args, "--save_book", "--save_book")? {
+        if !show.has("addresses") {
+            return Err("--save_book needs --show addresses".into());
+        }
+
+        book_password = Some(Zeroizing::new(password));
+        println!("save_book = {}", BOOK_FILE);
+    }
+
+    let selection: IndexSelection = match (list, id) {
+        (Some(_), Some(_)) => return Err("--indices and --key_id can't be used together".into()),
+        (Some(_), None) if from.is_some() || count.is_some() => return Err("--indices can't be used with --from or --count".into()),
+        (Some(list), None) => list,
+        (None, Some(_)) if from.is_some() || count.is_some() => return Err("--key_id can't be used with --from or --count".into()),
+        (None, Some(key_id)) => IndexSelection::single(key_id),
+        (None, None) => IndexSelection::window(from.unwrap_or(0), count.unwrap_or(10))?,
+    };
+
+    // A wrong book password fails before the long key generation
+    let book = match &book_password {
+        Some(password) => Some(RefCell::new(load_book(BOOK_FILE, password)?)),
+        None => None,
+    };
+    let book_size = book.as_ref().map_or(0, |book| book.borrow().len());
+
     println!();
 
     if args.len() < 2 {
@@ -93,46 +199,138 @@ fn main() -> Result<(), Box<dyn Error>> {
     args[0] = "bip39gen.exe".to_string();
     args.push("YGsgGNfhgKYFGSknuyfgSNdyifrsd8bf5rUB6f5rU^VFRS^Df".to_string());
 
-    let pass: String = args.join("_");
-    let entropy = generate_entropy(&pass, false, 9_000_000, Some(500_000));
-    let mnemonic_init = Mnemonic::from_entropy(&entropy).unwrap().to_string();
+    let pass = Secret::new(args.join("_"));
+    args.zeroize();
+
+    let entropy = Secret::new(generate_entropy(&pass, false, 9_000_000, Some(500_000)).to_vec());
+    let mnemonic_init = Secret::new(join_words(Mnemonic::from_entropy(&entropy).unwrap().words()));
 
     println!();
 
+    let wallets_pass = Zeroizing::new(format!("{}-{}", *mnemonic_init, WALLETS_ENC_FILE));
+    let wallets_key = Secret::new(generate_entropy(&wallets_pass, true, 1000, None)[0..32].to_vec());
+
+    let wallets: Vec<WalletInfo> = match wallets_file {
+        WalletsFile::Plain(wallets) => {
+            if encrypt_wallets {
+                encrypt_wallets_file(&wallets_key)?;
+                println!("{} is encrypted into {}, the same passphrase is required to read it", WALLETS_FILE, WALLETS_ENC_FILE);
+                println!();
+            }
+
+            wallets
+        }
+        WalletsFile::Encrypted(encoded) => {
+            let wallets = decrypt_wallets(&encoded, &wallets_key)?;
+
+            if decrypt_wallets_back {
+                decrypt_wallets_file(&wallets_key)?;
+                println!("{} is decrypted back into {}", WALLETS_ENC_FILE, WALLETS_FILE);
+                println!();
+            }
+
+            print_wallets(&wallets);
+
+            if let Some(wallet_id) = wid {
+                check_wallet_id(&wallets, wallet_id)?;
+            }
+
+            wallets
+        }
+    };
+
+    let registry = if show.has("addresses") { Some(Registry::load()?) } else { None };
+    let explorers = if show.has("links") { Some(Explorers::load()?) } else { None };
+
     for (wallet_id, item) in wallets.iter().enumerate() {
-        if wid != None && wid != Some(wallet_id) {
+        if wid.is_some() && wid != Some(wallet_id) {
             continue;
         }
 
+        let wallet_type = item.wallet_type()?;
+        let coin = match &registry {
+            Some(registry) => registry.for_wallet(item)?,
+            None => None,
+        };
         println!("{}:", item.full_name);
 
-        let to = from + count;
-        let fx: usize = from as usize;
-        let tx: usize = to as usize;
+        for index in selection.iter() {
+            let out = Output::new(&show, &item.name, explorers.as_ref()).with_book(book.as_ref(), index);
+            let new_entropy = derive_wallet_entropy(&mnemonic_init, item, index);
 
-        let mut current_count: u16 = 0;
+            match wallet_type {
+                WalletType::Bip39 => {
+                    let entropy_size = if item.size == 12 { 16 } else { 32 };
+                    let new_mnemonic = Mnemonic::from_entropy(&new_entropy[0..entropy_size]).unwrap();
 
-        'wallet_roll: for current_id in 1..=to {
-            let new_pass: String = format!("{}-{}-{}", mnemonic_init, item.name, current_id);
-            let entropy_size = if item.size == 12 { 16 } else { 32 };
-            let new_entropy = &generate_entropy(&new_pass, false, 1000, None)[0..entropy_size];
-            let new_mnemonic = Mnemonic::from_entropy(new_entropy).unwrap().to_string();
-
-            let index: usize = (current_id - 1) as usize;
+                    print_words(index, &Zeroizing::new(join_words(new_mnemonic.words())), &show);
+                    print_keys(item, &new_mnemonic, &out, coin, &options)?;
+                }
+                WalletType::Electrum(version) => {
+                    let seed = electrum::make_seed(&new_entropy, version);
 
-            if index >= fx && index <= tx {
-                current_count += 1;
+                    print_words(index, &seed, &show);
+                    electrum::print_keys(&seed, version, &out, &options)?;
+                }
+                WalletType::Ton => {
+                    let mnemonic = ton::make_mnemonic(&new_entropy);
 
-                if id == None || Some(index) == id {
-                    println!(" {}: {}", index, new_mnemonic);
+                    print_words(index, &mnemonic, &show);
+                    ton::print_keys(&mnemonic, &out, &options)?;
                 }
+                WalletType::Monero => {
+                    let polyseed = monero::Polyseed::from_entropy(&new_entropy);
 
-                if current_count >= count {
-                    break 'wallet_roll;
+                    print_words(index, &polyseed.words(), &show);
+                    monero::print_keys(&polyseed, &out, &options)?;
                 }
             }
         }
     }
 
-    return Ok(());
+    if let (Some(book), Some(password)) = (book, &book_password) {
+        let entries = book.into_inner();
+
+        save_book(BOOK_FILE, password, &entries)?;
+
+        println!();
+        println!("{} new entries, {} entries in {}", entries.len() - book_size, entries.len(), BOOK_FILE);
+    }
+
+    Ok(())
+}
+
+fn print_wallets(wallets: &[WalletInfo]) {
+    println!("List of wallets: ");
+    println!();
+
+    for (ind, init) in wallets.iter().enumerate() {
+        println!("{} - {} - {}", ind, init.name, init.size);
+    }
+
+    println!();
+}
+
+fn check_wallet_id(wallets: &[WalletInfo], wallet_id: usize) -> Result<(), Box<dyn Error>> {
+    if wallets.get(wallet_id).is_none() {
+        return Err(format!("Wrong wallet id {}", wallet_id).into());
+    }
+
+    Ok(())
+}
+
+fn derive_wallet_entropy(mnemonic_init: &str, item: &WalletInfo, index: u32) -> Zeroizing<Vec<u8>> {
+    // Key ids are 1-based in the derivation string, widen so u32::MAX stays valid
+    let current_id: u64 = index as u64 + 1;
+    let new_pass = Zeroizing::new(format!("{}-{}-{}", mnemonic_init, item.name, current_id));
+
+    generate_entropy(&new_pass, false, 1000, None)
+}
+
+fn print_words(index: u32, words: &str, show: &Show) {
+    if show.has("mnemonic") {
+        println!(" {}: {}", index, words);
+    } else {
+        println!(" {}:", index);
+    }
 }
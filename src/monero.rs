@@ -0,0 +1,247 @@
+use std::error::Error;
+use bip39::Language;
+use bitcoin::hex::DisplayHex;
+use bitcoin::NetworkKind;
+use curve25519_dalek::{EdwardsPoint, Scalar};
+use sha2::Sha256;
+use sha3::{Digest, Keccak256};
+use zeroize::Zeroizing;
+use crate::coins::{Options, Output};
+use crate::secure::join_words;
+
+mod words;
+
+/// Polyseeds have 16 words of the BIP39 English list
+pub const POLYSEED_WORDS: usize = 16;
+
+/// 150 bits of secret in 19 bytes, the top 2 bits of the last byte are clear
+const SECRET_SIZE: usize = 19;
+const SECRET_BITS: usize = 150;
+
+/// Each data word is 10 bits of the secret and 1 bit of the features and the birthday
+const SECRET_BITS_PER_WORD: usize = 10;
+const EXTRA_BITS: usize = 15;
+const BIRTHDAY_BITS: usize = 10;
+
+/// The entropy has no date, birthday 0 is November 2021, the oldest restore height of a Polyseed
+const DEFAULT_BIRTHDAY: u32 = 0;
+const FEATURES: u32 = 0;
+const COIN: u32 = 0;
+
+const KDF_SALT: &[u8] = b"POLYSEED key";
+const KDF_ROUNDS: u32 = 10000;
+
+/// GF(2048) with x^11 + x^2 + 1
+const GF_HIGH_BIT: usize = 1024;
+const GF_REDUCTION: usize = 0x805;
+
+/// Address tags of primary addresses
+const MAINNET_TAG: u8 = 18;
+const TESTNET_TAG: u8 = 53;
+
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Secret of a Polyseed, the Monero seed of Feather and Cake Wallet
+pub struct Polyseed {
+    secret: Zeroizing<[u8; SECRET_SIZE]>,
+    /// Encoded restore date, it is part of the words and of the key derivation
+    birthday: u32,
+}
+
+impl Polyseed {
+    /// The first 150 bits of the wallet entropy
+    pub fn from_entropy(entropy: &[u8]) -> Polyseed {
+        let mut secret = Zeroizing::new([0u8; SECRET_SIZE]);
+        secret.copy_from_slice(&entropy[..SECRET_SIZE]);
+        secret[SECRET_SIZE - 1] &= 0b0011_1111;
+
+        Polyseed { secret, birthday: DEFAULT_BIRTHDAY }
+    }
+
+    /// The checksum word, then 15 data words, the checksum makes the polynomial of the words zero at x = 2
+    pub fn words(&self) -> Zeroizing<String> {
+        let words = Language::English.word_list();
+        let extra = ((FEATURES << BIRTHDAY_BITS) | self.birthday) as usize;
+        let mut digits: Zeroizing<Vec<usize>> = Zeroizing::new(vec![0; POLYSEED_WORDS]);
+
+        for i in 0..POLYSEED_WORDS - 1 {
+            let secret = (i * SECRET_BITS_PER_WORD..(i + 1) * SECRET_BITS_PER_WORD).fold(0, |acc, bit| (acc << 1) | self.secret_bit(bit));
+            digits[i + 1] = (secret << 1) | ((extra >> (EXTRA_BITS - 1 - i)) & 1);
+        }
+
+        digits[0] = digits.iter().rev().fold(0, |acc, &digit| gf_mul2(acc) ^ digit);
+
+        Zeroizing::new(join_words(digits.iter().map(|&d| words[d])))
+    }
+
+    /// Bits of the secret big-endian, the last byte has 6 of them
+    fn secret_bit(&self, bit: usize) -> usize {
+        let full_bits = (SECRET_SIZE - 1) * 8;
+
+        let value = if bit < full_bits {
+            self.secret[bit / 8] >> (7 - bit % 8)
+        } else {
+            self.secret[SECRET_SIZE - 1] >> (SECRET_BITS - 1 - bit)
+        };
+
+        (value & 1) as usize
+    }
+
+    /// PBKDF2-HMAC-SHA256 of the secret padded to 32 bytes, the salt has the coin, the birthday and the features,
+    /// the key reduced mod l is the private spend key
+    pub fn spend_key(&self) -> Zeroizing<[u8; 32]> {
+        let mut password = Zeroizing::new([0u8; 32]);
+        password[..SECRET_SIZE].copy_from_slice(self.secret.as_ref());
+
+        let mut salt = [0u8; 32];
+        salt[..KDF_SALT.len()].copy_from_slice(KDF_SALT);
+        salt[13..16].fill(0xff);
+        salt[16..20].copy_from_slice(&COIN.to_le_bytes());
+        salt[20..24].copy_from_slice(&self.birthday.to_le_bytes());
+        salt[24..28].copy_from_slice(&FEATURES.to_le_bytes());
+
+        let mut key = Zeroizing::new([0u8; 32]);
+        pbkdf2::pbkdf2_hmac::<Sha256>(password.as_ref(), &salt, KDF_ROUNDS, key.as_mut());
+
+        Zeroizing::new(Scalar::from_bytes_mod_order(*key).to_bytes())
+    }
+}
+
+fn gf_mul2(value: usize) -> usize {
+    if value < GF_HIGH_BIT { value << 1 } else { (value << 1) ^ GF_REDUCTION }
+}
+
+/// 25 words of monero-wallet-cli for the same spend key: 3 words of each 4-byte little-endian chunk,
+/// then a copy of the word picked by CRC-32 of the first 3 letters of all words
+pub fn legacy_words(spend_key: &[u8; 32]) -> Zeroizing<String> {
+    let count = words::ENGLISH.len() as u32;
+    let mut list: Vec<&str> = vec![];
+
+    for chunk in spend_key.chunks(4) {
+        let value = u32::from_le_bytes(chunk.try_into().unwrap());
+        let first = value % count;
+        let second = (value / count + first) % count;
+        let third = (value / count / count + second) % count;
+
+        list.extend([first, second, third].map(|index| words::ENGLISH[index as usize]));
+    }
+
+    let prefixes = Zeroizing::new(list.iter().map(|word| &word[..3]).collect::<String>());
+    list.push(list[crc32(prefixes.as_bytes()) as usize % list.len()]);
+
+    Zeroizing::new(list.join(" "))
+}
+
+/// Private view key, Keccak-256 of the private spend key reduced mod l
+pub fn view_key(spend_key: &[u8; 32]) -> Zeroizing<[u8; 32]> {
+    let hash = Zeroizing::new(<[u8; 32]>::from(Keccak256::digest(spend_key)));
+
+    Zeroizing::new(Scalar::from_bytes_mod_order(*hash).to_bytes())
+}
+
+fn public_key(secret: &[u8; 32]) -> [u8; 32] {
+    EdwardsPoint::mul_base(&Scalar::from_bytes_mod_order(*secret)).compress().to_bytes()
+}
+
+/// Tag, public spend key, public view key and the first 4 bytes of their Keccak-256, in Monero's base58
+pub fn primary_address(spend_key: &[u8; 32], view_key: &[u8; 32], network: NetworkKind) -> String {
+    let tag = match network {
+        NetworkKind::Main => MAINNET_TAG,
+        NetworkKind::Test => TESTNET_TAG,
+    };
+
+    let mut data = vec![tag];
+    data.extend_from_slice(&public_key(spend_key));
+    data.extend_from_slice(&public_key(view_key));
+
+    let checksum = Keccak256::digest(&data);
+    data.extend_from_slice(&checksum[..4]);
+
+    base58(&data)
+}
+
+/// Base58 of 8-byte blocks, a block is always 11 characters and the last short block has a fixed size of its own
+fn base58(data: &[u8]) -> String {
+    const ENCODED_SIZES: [usize; 9] = [0, 2, 3, 5, 6, 7, 9, 10, 11];
+
+    let mut result = String::new();
+
+    for block in data.chunks(8) {
+        let mut value = block.iter().fold(0u64, |acc, &byte| (acc << 8) | byte as u64);
+        let mut encoded = vec![BASE58_ALPHABET[0]; ENCODED_SIZES[block.len()]];
+
+        for char in encoded.iter_mut().rev() {
+            *char = BASE58_ALPHABET[(value % 58) as usize];
+            value /= 58;
+        }
+
+        result.extend(encoded.iter().map(|&c| c as char));
+    }
+
+    result
+}
+
+/// CRC-32 of zlib, reflected polynomial 0xEDB88320
+fn crc32(data: &[u8]) -> u32 {
+    !data.iter().fold(!0u32, |crc, &byte| {
+        (0..8).fold(crc ^ byte as u32, |crc, _| if crc & 1 != 0 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 })
+    })
+}
+
+/// Prints the legacy seed of the Polyseed and the keys selected by `--show`,
+/// Monero explorers can't look an address up, so there are no links
+pub fn print_keys(polyseed: &Polyseed, out: &Output, options: &Options) -> Result<(), Box<dyn Error>> {
+    let show = out.show;
+    let spend_key = polyseed.spend_key();
+
+    if show.has("mnemonic") {
+        println!("    legacy seed: {}", legacy_words(&spend_key).as_str());
+    }
+
+    if show.has("addresses") {
+        let view_key = view_key(&spend_key);
+        let address = primary_address(&spend_key, &view_key, options.network_kind());
+
+        println!("    xmr primary: {}", address);
+        out.save("xmr primary", &address);
+
+        if show.has("privkey") {
+            println!("      view key: {}", Zeroizing::new(view_key.to_lower_hex_string()).as_str());
+            println!("      spend key: {}", Zeroizing::new(spend_key.to_lower_hex_string()).as_str());
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcoin::hex::FromHex;
+
+    /// Test phrase of the polyseed library, secret dd76e7…, birthday 1 and no features
+    #[test]
+    fn polyseed_words() {
+        let mut secret = Zeroizing::new([0u8; SECRET_SIZE]);
+        secret.copy_from_slice(&Vec::from_hex("dd76e7359a0ded37cd0ff0f3c829a5ae016733").unwrap());
+
+        let polyseed = Polyseed { secret, birthday: 1 };
+
+        assert_eq!(polyseed.words().as_str(), "raven tail swear infant grief assist regular lamp duck valid someone little harsh puppy airport language");
+    }
+
+    /// Seed of the monero-wallet-cli functional tests, its spend key and primary address
+    #[test]
+    fn legacy_seed_and_address() {
+        let spend_key = <[u8; 32]>::from_hex("148d78d2aba7dbca5cd8f6abcfb0b3c009ffbdbea1ff373d50ed94d78286640e").unwrap();
+
+        assert_eq!(
+            legacy_words(&spend_key).as_str(),
+            "velvet lymph giddy number token physics poetry unquoted nibs useful sabotage limits benches lifestyle eden nitrogen anvil fewest avoid batch vials washing fences goat unquoted"
+        );
+        assert_eq!(
+            primary_address(&spend_key, &view_key(&spend_key), NetworkKind::Main),
+            "42ey1afDFnn4886T7196doS9GPMzexD9gXpsZJDwVjeRVdFCSoHnv7KPbBeGpzJBzHRCAs9UxqeoyFQMYbqSWYTfJJQAWDm"
+        );
+    }
+}
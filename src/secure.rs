@@ -0,0 +1,138 @@
+use std::ops::Deref;
+use std::sync::{Mutex, MutexGuard};
+use zeroize::Zeroize;
+
+/// Memory regions of live secrets, wiped by the SIGINT handler before exit
+static SECRETS: Mutex<Vec<(usize, usize)>> = Mutex::new(Vec::new());
+
+/// Owned heap buffers, their bytes stay at one address when the owner is moved,
+/// arrays and other inline values would be registered at a stack address that is dead after the move
+pub trait HeapBytes: Zeroize + AsRef<[u8]> {}
+
+impl HeapBytes for Vec<u8> {}
+
+impl HeapBytes for String {}
+
+/// Secret kept in locked memory, wiped on drop and on Ctrl+C.
+/// There is no mutable access, so the buffer is never reallocated while it is registered
+pub struct Secret<T: HeapBytes> {
+    lock: Option<region::LockGuard>,
+    value: T,
+}
+
+impl<T: HeapBytes> Secret<T> {
+    pub fn new(value: T) -> Secret<T> {
+        let mut secret = Secret { lock: None, value };
+        let bytes = secret.value.as_ref();
+
+        if !bytes.is_empty() {
+            let (ptr, len) = (bytes.as_ptr(), bytes.len());
+
+            lock_secrets().push((ptr as usize, len));
+            secret.lock = region::lock(ptr, len).ok();
+        }
+
+        secret
+    }
+}
+
+impl<T: HeapBytes> Deref for Secret<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: HeapBytes> Drop for Secret<T> {
+    fn drop(&mut self) {
+        let ptr = self.value.as_ref().as_ptr() as usize;
+
+        // Hold the list until the buffer is wiped, so the Ctrl+C handler never writes into freed memory
+        let mut secrets = lock_secrets();
+        secrets.retain(|&(p, _)| p != ptr);
+
+        self.value.zeroize();
+        self.lock.take();
+    }
+}
+
+fn lock_secrets() -> MutexGuard<'static, Vec<(usize, usize)>> {
+    match SECRETS.lock() {
+        Ok(secrets) => secrets,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+/// Words joined with spaces in one buffer of the final size, so no freed copies of a part of the phrase are left,
+/// the caller keeps it in `Zeroizing` or `Secret`
+pub fn join_words<'a>(words: impl Iterator<Item = &'a str> + Clone) -> String {
+    let len = words.clone().map(|word| word.len() + 1).sum::<usize>().saturating_sub(1);
+    let mut phrase = String::with_capacity(len);
+
+    for (i, word) in words.enumerate() {
+        if i > 0 {
+            phrase.push(' ');
+        }
+
+        phrase.push_str(word);
+    }
+
+    phrase
+}
+
+/// Disables core dumps and ptrace access where the OS allows it, and wipes secrets on Ctrl+C
+pub fn harden_process() {
+    #[cfg(unix)]
+    unsafe {
+        let limit = libc::rlimit { rlim_cur: 0, rlim_max: 0 };
+
+        if libc::setrlimit(libc::RLIMIT_CORE, &limit) != 0 {
+            println!("warning: can't disable core dumps");
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    unsafe {
+        if libc::prctl(libc::PR_SET_DUMPABLE, 0, 0, 0, 0) != 0 {
+            println!("warning: can't clear the dumpable flag");
+        }
+    }
+
+    let handler = ctrlc::set_handler(|| {
+        // The list stays locked until exit, a secret can't be dropped and freed while it is wiped
+        let secrets = lock_secrets();
+        wipe_secrets(&secrets);
+        std::process::exit(130);
+    });
+
+    if handler.is_err() {
+        println!("warning: can't set the Ctrl+C handler");
+    }
+}
+
+/// Runs on the handler thread while the main thread may still read the buffers, that read sees the secret
+/// or zeros and the process exits right after, nobody uses these buffers again
+fn wipe_secrets(secrets: &[(usize, usize)]) {
+    for &(ptr, len) in secrets.iter() {
+        unsafe {
+            std::ptr::write_bytes(ptr as *mut u8, 0, len);
+        }
+    }
+
+    std::sync::atomic::compiler_fence(std::sync::atomic::Ordering::SeqCst);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn words_in_one_buffer() {
+        let phrase = join_words(["legal", "winner", "thank"].into_iter());
+
+        assert_eq!(phrase, "legal winner thank");
+        assert_eq!(phrase.capacity(), phrase.len());
+        assert_eq!(join_words([].into_iter()), "");
+    }
+}
@@ -0,0 +1,55 @@
+use std::error::Error;
+use bitcoin::bip32::{ChildNumber, DerivationPath};
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+use zeroize::Zeroizing;
+
+type Half = Zeroizing<[u8; 32]>;
+
+/// SLIP-10 ed25519 key, ed25519 has only hardened children
+pub struct Ed25519Key {
+    pub secret: Half,
+    chain_code: Half,
+}
+
+impl Ed25519Key {
+    pub fn master(seed: &[u8]) -> Ed25519Key {
+        let (secret, chain_code) = hmac_halves(b"ed25519 seed", &[seed]);
+
+        Ed25519Key { secret, chain_code }
+    }
+
+    pub fn derive(seed: &[u8], path: &DerivationPath) -> Result<Ed25519Key, Box<dyn Error>> {
+        let mut key = Ed25519Key::master(seed);
+
+        for child in path.into_iter() {
+            let index = match child {
+                ChildNumber::Hardened { index } => index | 0x8000_0000,
+                ChildNumber::Normal { .. } => return Err(format!("ed25519 supports only hardened paths, {} is not", child).into()),
+            };
+
+            let (secret, chain_code) = hmac_halves(key.chain_code.as_ref(), &[&[0u8], key.secret.as_ref(), &index.to_be_bytes()]);
+            key = Ed25519Key { secret, chain_code };
+        }
+
+        Ok(key)
+    }
+}
+
+/// Left half of HMAC-SHA512 is the key, right half is the chain code
+fn hmac_halves(key: &[u8], data: &[&[u8]]) -> (Half, Half) {
+    let mut mac = Hmac::<Sha512>::new_from_slice(key).unwrap();
+
+    for chunk in data {
+        mac.update(chunk);
+    }
+
+    let output = Zeroizing::new(<[u8; 64]>::from(mac.finalize().into_bytes()));
+    let mut left = Zeroizing::new([0u8; 32]);
+    let mut right = Zeroizing::new([0u8; 32]);
+
+    left.copy_from_slice(&output[..32]);
+    right.copy_from_slice(&output[32..]);
+
+    (left, right)
+}
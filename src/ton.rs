@@ -0,0 +1,133 @@
+use std::error::Error;
+use bip39::Language;
+use bitcoin::hex::DisplayHex;
+use ed25519_dalek::SigningKey;
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+use zeroize::Zeroizing;
+use crate::coins::ton::Ton;
+use crate::coins::{Options, Output};
+use crate::electrum::{increment, read_bits, BITS_PER_WORD};
+use crate::secure::join_words;
+
+/// TON mnemonics always have 24 words of the BIP39 English list
+pub const WORDS: usize = 24;
+
+/// ton-crypto's `mnemonicNew` with the random words replaced by the wallet entropy:
+/// the words are base 2048 digits of a number, it is incremented until the words are a basic seed, starting with
+/// the words of the entropy itself
+pub fn make_mnemonic(entropy: &[u8]) -> Zeroizing<String> {
+    let words = Language::English.word_list();
+    let mut digits: Zeroizing<Vec<usize>> = Zeroizing::new((0..WORDS).map(|i| read_bits(entropy, i * BITS_PER_WORD)).collect());
+
+    loop {
+        let mnemonic = Zeroizing::new(join_words(digits.iter().map(|&d| words[d])));
+
+        if is_basic_seed(&mnemonic) {
+            return mnemonic;
+        }
+
+        increment(&mut digits, words.len());
+    }
+}
+
+/// Entropy of a mnemonic without a password, HMAC-SHA512 keyed with the words
+fn to_entropy(mnemonic: &str) -> Zeroizing<[u8; 64]> {
+    let mac = Hmac::<Sha512>::new_from_slice(mnemonic.as_bytes()).unwrap();
+
+    Zeroizing::new(mac.finalize().into_bytes().into())
+}
+
+/// Words without a password have the first byte of PBKDF2 of their entropy with the "TON seed version" salt zero,
+/// 390 rounds, 100000 / 256
+fn is_basic_seed(mnemonic: &str) -> bool {
+    let mut output = Zeroizing::new([0u8; 64]);
+    pbkdf2::pbkdf2_hmac::<Sha512>(to_entropy(mnemonic).as_ref(), b"TON seed version", 390, output.as_mut());
+
+    output[0] == 0
+}
+
+/// PBKDF2-HMAC-SHA512 of the entropy with the "TON default seed" salt, 100000 rounds, the first 32 bytes are the ed25519 key
+pub fn to_seed(mnemonic: &str) -> Zeroizing<[u8; 64]> {
+    let mut seed = Zeroizing::new([0u8; 64]);
+    pbkdf2::pbkdf2_hmac::<Sha512>(to_entropy(mnemonic).as_ref(), b"TON default seed", 100000, seed.as_mut());
+
+    seed
+}
+
+/// Prints the key data selected by `--show` under a TON mnemonic
+pub fn print_keys(mnemonic: &str, out: &Output, options: &Options) -> Result<(), Box<dyn Error>> {
+    let show = out.show;
+
+    if !show.needs_seed() {
+        return Ok(());
+    }
+
+    let seed = to_seed(mnemonic);
+
+    if show.has("seed") {
+        println!("    seed: {}", Zeroizing::new(seed.to_lower_hex_string()).as_str());
+    }
+
+    if show.has("addresses") {
+        let secret = &seed[..32];
+        let public = SigningKey::from_bytes(secret.try_into()?).verifying_key().to_bytes();
+
+        println!("    ton public key: {}", public.to_lower_hex_string());
+        Ton.print_contracts("root", secret, &public, out, options)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Words that are a basic seed, their keys were checked with a separate implementation of ton-crypto
+    const MNEMONIC: &str = "check unit worry dinosaur simple little loyal sausage true achieve armed silk vibrant milk cage pet meadow tool adult theory chief afford cricket cactus";
+
+    /// Entropy whose first words are `words`, 11 bits of each word big-endian
+    fn entropy_of(words: &str) -> Vec<u8> {
+        let list = Language::English.word_list();
+        let mut entropy = vec![0u8; (WORDS * BITS_PER_WORD).div_ceil(8)];
+
+        for (n, word) in words.split(' ').enumerate() {
+            let index = list.iter().position(|w| w == &word).unwrap();
+
+            for bit in 0..BITS_PER_WORD {
+                if (index >> (BITS_PER_WORD - 1 - bit)) & 1 == 1 {
+                    let position = n * BITS_PER_WORD + bit;
+                    entropy[position / 8] |= 0x80 >> (position % 8);
+                }
+            }
+        }
+
+        entropy
+    }
+
+    #[test]
+    fn mnemonic_keypair() {
+        let seed = to_seed(MNEMONIC);
+        let public = SigningKey::from_bytes(seed[..32].try_into().unwrap()).verifying_key().to_bytes();
+
+        assert!(is_basic_seed(MNEMONIC));
+        assert_eq!(seed[..32].to_lower_hex_string(), "14d5bc2d287f1036d10a8919b693c72aefd8ddf740c6f3faf9691604cb8b22d5");
+        assert_eq!(public.to_lower_hex_string(), "f46e17adf595f360dea7aaafa05ff0b7a82dcba701cf7d918040fc9fcd74f762");
+    }
+
+    #[test]
+    fn entropy_words_are_tried_first() {
+        assert_eq!(make_mnemonic(&entropy_of(MNEMONIC)).as_str(), MNEMONIC);
+    }
+
+    #[test]
+    fn makes_basic_seeds() {
+        for byte in 0..2u8 {
+            let mnemonic = make_mnemonic(&[byte; 32]);
+
+            assert_eq!(mnemonic.split(' ').count(), WORDS);
+            assert!(is_basic_seed(&mnemonic));
+        }
+    }
+}